@@ -1,6 +1,7 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use version_number::parsers::modular::Parser as ModularParser;
 use version_number::parsers::original::Parser as OriginalParser;
+use version_number::ParserError;
 
 fn current_parse(input: &str) {
     let _ = OriginalParser::from_slice(input.as_bytes()).parse();
@@ -71,5 +72,85 @@ pub fn parse_benchmark(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, parse_benchmark,);
+// A bulk workload, in which most inputs are invalid, so the error path dominates.
+fn error_heavy_inputs() -> Vec<String> {
+    let invalid = [
+        "00.1.1",
+        "1.02",
+        "1.2.3.4",
+        "1.2.3-beta",
+        "v1.2.3",
+        " 1.2",
+        "1,1,1",
+        "unexpected",
+        "18446744073709551616.0.0",
+    ];
+
+    (0..1000)
+        .map(|i| match i % 10 {
+            9 => format!("{}.{}.{}", i, i % 7, i % 3),
+            n => format!("{}{}", invalid[n], i),
+        })
+        .collect()
+}
+
+pub fn error_heavy_benchmark(c: &mut Criterion) {
+    let inputs = error_heavy_inputs();
+    let mut group = c.benchmark_group("ErrorHeavy");
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+
+    group.bench_function("current[borrowed]", |b| {
+        b.iter(|| {
+            inputs
+                .iter()
+                .filter(|input| {
+                    OriginalParser::from_slice(input.as_bytes())
+                        .parse()
+                        .is_err()
+                })
+                .count()
+        })
+    });
+
+    group.bench_function("current[owned]", |b| {
+        b.iter(|| {
+            inputs
+                .iter()
+                .filter_map(|input| {
+                    OriginalParser::from_slice(input.as_bytes())
+                        .parse()
+                        .map_err(|e| e.into_owned())
+                        .err()
+                })
+                .count()
+        })
+    });
+
+    group.bench_function("current[parser_error]", |b| {
+        b.iter(|| {
+            inputs
+                .iter()
+                .filter_map(|input| {
+                    OriginalParser::from_slice(input.as_bytes())
+                        .parse()
+                        .map_err(ParserError::from)
+                        .err()
+                })
+                .count()
+        })
+    });
+
+    group.bench_function("modular", |b| {
+        b.iter(|| {
+            inputs
+                .iter()
+                .filter(|input| ModularParser::from_slice(input.as_bytes()).parse().is_err())
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parse_benchmark, error_heavy_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use version_number::scanner::{Boundary, Scanner};
+
+// A log of a few megabytes, in which versions are rare compared to other numbers.
+fn log(lines: usize) -> String {
+    (0..lines)
+        .map(|i| match i % 4 {
+            0 => format!(
+                "[{:08}] INFO compiling foo v1.{}.{} (/build/foo)\n",
+                i,
+                i % 90,
+                i % 7
+            ),
+            1 => format!(
+                "[{:08}] DEBUG connecting to 192.168.{}.{}:8080\n",
+                i,
+                i % 256,
+                i % 100
+            ),
+            2 => format!(
+                "[{:08}] WARN took {}.{}s, requires Rust 1.65 or newer\n",
+                i,
+                i % 10,
+                i % 100
+            ),
+            _ => format!(
+                "[{:08}] TRACE payload=0x{:x} sha=a1b2c3d4e5f6\n",
+                i,
+                i * 7919
+            ),
+        })
+        .collect()
+}
+
+pub fn scan_benchmark(c: &mut Criterion) {
+    let input = log(50_000);
+    let mut group = c.benchmark_group("Scanner");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    for (name, scanner) in [
+        ("word", Scanner::new()),
+        ("none", Scanner::new().with_boundary(Boundary::None)),
+    ]
+    .iter()
+    {
+        group.bench_with_input(BenchmarkId::new("log", name), &input, |b, input| {
+            b.iter(|| scanner.scan(input).count())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, scan_benchmark);
+criterion_main!(benches);
@@ -4,5 +4,5 @@ fn main() {
     // Additional labels such as build flags are not supported!
     let err = Version::parse("1.0.0-alpha").unwrap_err();
 
-    eprintln!("{err}"); // prints: Expected end of input, but got '-' at 5.
+    eprintln!("{err}"); // prints: Expected 0-9 or EOI, but got '-' (at 5..6)
 }
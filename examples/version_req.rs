@@ -0,0 +1,12 @@
+use version_number::{Version, VersionReq};
+
+fn main() {
+    let requirement = VersionReq::parse(">=1.56, <1.70").unwrap();
+
+    for input in ["1.55", "1.56", "1.69.2", "1.70.0"] {
+        let version = Version::parse(input).unwrap();
+        let matches = requirement.matches(&version);
+
+        println!("{version} matches '{requirement}': {matches}");
+    }
+}
@@ -0,0 +1,281 @@
+//! Classify the change between two versions, and check whether they are compatible by the rules
+//! of Cargo.
+//!
+//! Cargo considers two versions compatible if they agree on their left-most non-zero component,
+//! or on all three components if both `major` and `minor` are `0`. So `1.2.3` and `1.5.0` are
+//! compatible, while `0.2.3` and `0.3.0`, or `0.0.3` and `0.0.4`, are not. Compatible versions
+//! share the same [`CompatibilityKey`], which is also the prefix by which Cargo deduplicates the
+//! versions of a dependency in a lockfile.
+//!
+//! A two component [`BaseVersion`] `major.minor` is treated as `major.minor.0`, the same way
+//! Cargo treats a two component `rust-version`.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::compat::{is_compatible, ChangeKind};
+//! use version_number::{BaseVersion, FullVersion};
+//!
+//! let old = FullVersion::new(0, 2, 3);
+//! let new = FullVersion::new(0, 3, 0);
+//!
+//! assert_eq!(ChangeKind::between(old, new), ChangeKind::Minor);
+//! assert!(!is_compatible(old, new));
+//!
+//! assert_eq!(old.compatibility_key().to_string(), "0.2");
+//! assert!(is_compatible(BaseVersion::new(1, 56), FullVersion::new(1, 70, 0)));
+//! ```
+//!
+//! [`BaseVersion`]: crate::BaseVersion
+
+use crate::{BaseVersion, FullVersion, Version};
+use core::fmt;
+
+/// The left-most component in which two versions differ.
+///
+/// The direction of the change is not considered, so both `1.2.3` to `1.3.0`, and `1.3.0` to
+/// `1.2.3` are a [`ChangeKind::Minor`] change.
+///
+/// Kinds are ordered by the significance of the change, from [`ChangeKind::Unchanged`] to
+/// [`ChangeKind::Major`], so the most significant of a number of changes is their maximum.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ChangeKind {
+    /// The versions are equal.
+    Unchanged,
+    /// The versions only differ in their `patch` component.
+    Patch,
+    /// The versions differ in their `minor` component, and have the same `major` component.
+    Minor,
+    /// The versions differ in their `major` component.
+    Major,
+}
+
+impl ChangeKind {
+    /// Classify the change from the `old` to the `new` version.
+    ///
+    /// Accepts a [`Version`], [`BaseVersion`] or [`FullVersion`], where a [`BaseVersion`]
+    /// `major.minor` is treated as `major.minor.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::compat::ChangeKind;
+    /// use version_number::{BaseVersion, FullVersion};
+    ///
+    /// assert_eq!(ChangeKind::between(FullVersion::new(1, 2, 3), FullVersion::new(2, 0, 0)), ChangeKind::Major);
+    /// assert_eq!(ChangeKind::between(BaseVersion::new(1, 2), FullVersion::new(1, 2, 1)), ChangeKind::Patch);
+    /// ```
+    pub fn between(old: impl Into<Version>, new: impl Into<Version>) -> Self {
+        let old = to_full_version(&old.into());
+        let new = to_full_version(&new.into());
+
+        if old.major != new.major {
+            Self::Major
+        } else if old.minor != new.minor {
+            Self::Minor
+        } else if old.patch != new.patch {
+            Self::Patch
+        } else {
+            Self::Unchanged
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Unchanged => "unchanged",
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// The components which compatible versions have in common, by the rules of Cargo.
+///
+/// Displayed as the version prefix which it represents: `1` for `1.2.3`, `0.2` for `0.2.3`, and
+/// `0.0.3` for `0.0.3`. Keys are ordered like these prefixes.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompatibilityKey {
+    /// The key of a `0.0.patch` version.
+    Patch(u64),
+    /// The key of a `0.minor.patch` version, where `minor` is not `0`.
+    Minor(u64),
+    /// The key of a `major.minor.patch` version, where `major` is not `0`.
+    Major(u64),
+}
+
+impl fmt::Display for CompatibilityKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Patch(patch) => write!(f, "0.0.{}", patch),
+            Self::Minor(minor) => write!(f, "0.{}", minor),
+            Self::Major(major) => write!(f, "{}", major),
+        }
+    }
+}
+
+/// Whether the versions `a` and `b` are compatible by the rules of Cargo, i.e. whether they have
+/// the same [`CompatibilityKey`].
+///
+/// Accepts a [`Version`], [`BaseVersion`] or [`FullVersion`], where a [`BaseVersion`]
+/// `major.minor` is treated as `major.minor.0`.
+pub fn is_compatible(a: impl Into<Version>, b: impl Into<Version>) -> bool {
+    a.into().compatibility_key() == b.into().compatibility_key()
+}
+
+impl FullVersion {
+    /// The [`CompatibilityKey`] of this version, i.e. the prefix which it shares with each
+    /// compatible version.
+    ///
+    /// [`CompatibilityKey`]: crate::compat::CompatibilityKey
+    pub fn compatibility_key(&self) -> CompatibilityKey {
+        match (self.major, self.minor) {
+            (0, 0) => CompatibilityKey::Patch(self.patch),
+            (0, minor) => CompatibilityKey::Minor(minor),
+            (major, _) => CompatibilityKey::Major(major),
+        }
+    }
+}
+
+impl BaseVersion {
+    /// The [`CompatibilityKey`] of this version, where the version `major.minor` is treated as
+    /// `major.minor.0`.
+    ///
+    /// [`CompatibilityKey`]: crate::compat::CompatibilityKey
+    pub fn compatibility_key(&self) -> CompatibilityKey {
+        self.to_full_version_lossy().compatibility_key()
+    }
+}
+
+impl Version {
+    /// The [`CompatibilityKey`] of this version, where a two component version `major.minor` is
+    /// treated as `major.minor.0`.
+    ///
+    /// [`CompatibilityKey`]: crate::compat::CompatibilityKey
+    pub fn compatibility_key(&self) -> CompatibilityKey {
+        to_full_version(self).compatibility_key()
+    }
+}
+
+fn to_full_version(version: &Version) -> FullVersion {
+    match version {
+        Version::Base(base) => base.to_full_version_lossy(),
+        Version::Full(full) => *full,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_compatible, ChangeKind, CompatibilityKey};
+    use crate::{BaseVersion, FullVersion, Version};
+
+    #[yare::parameterized(
+        unchanged = { "1.2.3", "1.2.3", ChangeKind::Unchanged },
+        patch = { "1.2.3", "1.2.4", ChangeKind::Patch },
+        minor = { "1.2.3", "1.3.0", ChangeKind::Minor },
+        major = { "1.2.3", "2.0.0", ChangeKind::Major },
+        downgrade = { "1.3.0", "1.2.3", ChangeKind::Minor },
+        initial_development = { "0.2.3", "0.3.0", ChangeKind::Minor },
+        base_unchanged = { "1.2", "1.2.0", ChangeKind::Unchanged },
+        base_patch = { "1.2", "1.2.1", ChangeKind::Patch },
+        base_minor = { "1.2", "1.3", ChangeKind::Minor },
+        base_major = { "1.2", "2.0.0", ChangeKind::Major },
+    )]
+    fn change_kind(old: &str, new: &str, expected: ChangeKind) {
+        let old = Version::parse(old).unwrap();
+        let new = Version::parse(new).unwrap();
+
+        assert_eq!(ChangeKind::between(old, new), expected);
+    }
+
+    #[test]
+    fn change_kind_ord() {
+        let changes = [ChangeKind::Patch, ChangeKind::Major, ChangeKind::Unchanged];
+
+        assert_eq!(changes.iter().max(), Some(&ChangeKind::Major));
+        assert!(ChangeKind::Minor > ChangeKind::Patch);
+    }
+
+    #[yare::parameterized(
+        major = { "1.2.3", CompatibilityKey::Major(1), "1" },
+        minor = { "0.2.3", CompatibilityKey::Minor(2), "0.2" },
+        patch = { "0.0.3", CompatibilityKey::Patch(3), "0.0.3" },
+        zero = { "0.0.0", CompatibilityKey::Patch(0), "0.0.0" },
+        base_major = { "1.2", CompatibilityKey::Major(1), "1" },
+        base_minor = { "0.2", CompatibilityKey::Minor(2), "0.2" },
+        base_patch = { "0.0", CompatibilityKey::Patch(0), "0.0.0" },
+    )]
+    fn compatibility_key(version: &str, expected: CompatibilityKey, display: &str) {
+        let key = Version::parse(version).unwrap().compatibility_key();
+
+        assert_eq!(key, expected);
+        assert_eq!(key.to_string(), display);
+    }
+
+    #[test]
+    fn compatibility_key_of_each_type() {
+        let key = CompatibilityKey::Minor(2);
+
+        assert_eq!(FullVersion::new(0, 2, 3).compatibility_key(), key);
+        assert_eq!(BaseVersion::new(0, 2).compatibility_key(), key);
+        assert_eq!(Version::new_full_version(0, 2, 3).compatibility_key(), key);
+    }
+
+    #[yare::parameterized(
+        patch = { CompatibilityKey::Patch(3), CompatibilityKey::Patch(4) },
+        patch_minor = { CompatibilityKey::Patch(9), CompatibilityKey::Minor(1) },
+        minor_major = { CompatibilityKey::Minor(9), CompatibilityKey::Major(1) },
+        major = { CompatibilityKey::Major(1), CompatibilityKey::Major(2) },
+    )]
+    fn compatibility_key_less(lhs: CompatibilityKey, rhs: CompatibilityKey) {
+        assert!(lhs < rhs);
+    }
+
+    #[yare::parameterized(
+        same = { "1.2.3", "1.2.3", true },
+        patch = { "1.2.3", "1.2.4", true },
+        minor = { "1.2.3", "1.5.0", true },
+        major = { "1.2.3", "2.0.0", false },
+        zero_patch = { "0.2.3", "0.2.4", true },
+        zero_minor = { "0.2.3", "0.3.0", false },
+        zero_zero_patch = { "0.0.3", "0.0.4", false },
+        zero_zero_same = { "0.0.3", "0.0.3", true },
+        zero_and_one = { "0.9.0", "1.0.0", false },
+        base = { "1.56", "1.70.0", true },
+        base_zero = { "0.2", "0.2.5", true },
+        base_zero_zero = { "0.0", "0.0.1", false },
+    )]
+    fn compatible(a: &str, b: &str, expected: bool) {
+        let a = Version::parse(a).unwrap();
+        let b = Version::parse(b).unwrap();
+
+        assert_eq!(is_compatible(a.clone(), b.clone()), expected);
+        assert_eq!(is_compatible(b, a), expected);
+    }
+
+    #[test]
+    fn compatible_mixed_types() {
+        assert!(is_compatible(
+            BaseVersion::new(1, 56),
+            FullVersion::new(1, 70, 0)
+        ));
+        assert!(!is_compatible(
+            FullVersion::new(0, 2, 0),
+            Version::new_base_version(0, 3)
+        ));
+    }
+
+    #[yare::parameterized(
+        unchanged = { ChangeKind::Unchanged, "unchanged" },
+        patch = { ChangeKind::Patch, "patch" },
+        minor = { ChangeKind::Minor, "minor" },
+        major = { ChangeKind::Major, "major" },
+    )]
+    fn change_kind_display(kind: ChangeKind, expected: &str) {
+        assert_eq!(kind.to_string(), expected);
+    }
+}
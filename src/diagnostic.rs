@@ -0,0 +1,313 @@
+//! Implementations of [`miette::Diagnostic`] for the errors of the parsers.
+//!
+//! Each diagnostic has a stable error code, such as `version_number::leading_zero`, a label which
+//! points at the span of the input at which parsing failed, and, where possible, a help text.
+//!
+//! Only [`OriginalParserError`] keeps the input around, so only its diagnostic provides the source
+//! code. For the other errors, the input can be attached with
+//! [`miette::Report::with_source_code`].
+
+use crate::parsers::error::ErrorReason;
+use crate::parsers::modular::ModularParserError;
+use crate::parsers::original::OriginalParserError;
+use crate::parsers::requirement::RequirementParserError;
+use crate::parsers::{LabelError, NumericError, ParserError, Span, Token};
+use crate::Error;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+use std::fmt::Display;
+
+impl Diagnostic for ParserError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(code(self)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        help(self).map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(std::iter::once(label(self))))
+    }
+}
+
+impl Diagnostic for OriginalParserError<'_> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(code(&ParserError::from(self.clone()))))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        help(&ParserError::from(self.clone())).map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(self.source())
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(std::iter::once(label(&ParserError::from(
+            self.clone(),
+        )))))
+    }
+}
+
+impl Diagnostic for ModularParserError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(code(&ParserError::from(self.clone()))))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        help(&ParserError::from(self.clone())).map(|help| Box::new(help) as Box<dyn Display>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(std::iter::once(label(&ParserError::from(
+            self.clone(),
+        )))))
+    }
+}
+
+impl Diagnostic for Error {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Error::ParserError(e) => e.code(),
+            Error::RequirementParserError(e) => Some(Box::new(requirement_code(e))),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Error::ParserError(e) => e.help(),
+            Error::RequirementParserError(e) => {
+                requirement_help(e).map(|help| Box::new(help) as Box<dyn Display>)
+            }
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Error::ParserError(e) => e.labels(),
+            Error::RequirementParserError(e) => requirement_label(e)
+                .map(|label| Box::new(std::iter::once(label)) as Box<dyn Iterator<Item = _>>),
+        }
+    }
+}
+
+fn code(error: &ParserError) -> &'static str {
+    match error.reason() {
+        ErrorReason::Expected(_) => "version_number::unexpected_token",
+        ErrorReason::Numeric(NumericError::LeadingZero) => "version_number::leading_zero",
+        ErrorReason::Numeric(NumericError::Overflow) => "version_number::overflow",
+        ErrorReason::Label(LabelError::EmptyIdentifier) => {
+            "version_number::label::empty_identifier"
+        }
+        ErrorReason::Label(LabelError::InvalidCharacter { .. }) => {
+            "version_number::label::invalid_character"
+        }
+        ErrorReason::Label(LabelError::LeadingZero) => "version_number::label::leading_zero",
+    }
+}
+
+fn help(error: &ParserError) -> Option<String> {
+    let help = match error.reason() {
+        ErrorReason::Expected(_) => match error.got() {
+            Some('-' | '+') if !error.expected().contains(Token::Hyphen) => {
+                "pre-release labels and build metadata are not allowed here; a LabeledVersion accepts them"
+            }
+            Some(c) if c.is_whitespace() => "remove the whitespace",
+            None if error.expected().contains(Token::Dot) => {
+                "a version consists of two or three components, like `1.2` or `1.2.3`"
+            }
+            _ => return None,
+        },
+        ErrorReason::Numeric(error) => return Some(numeric_help(error)),
+        ErrorReason::Label(LabelError::EmptyIdentifier) => {
+            "remove the empty identifier, or the '-', '+' or '.' which precedes it"
+        }
+        ErrorReason::Label(LabelError::InvalidCharacter { .. }) => {
+            "labels may only contain ASCII alphanumerics, '-' and '.'"
+        }
+        ErrorReason::Label(LabelError::LeadingZero) => "remove the leading zero",
+    };
+
+    Some(help.to_string())
+}
+
+fn label(error: &ParserError) -> LabeledSpan {
+    let Span { start, end } = error.span();
+
+    let text = match error.reason() {
+        ErrorReason::Expected(e) => format!("expected {}", e.expected),
+        ErrorReason::Numeric(NumericError::LeadingZero) => "leading zero".to_string(),
+        ErrorReason::Numeric(NumericError::Overflow) => "number too large".to_string(),
+        ErrorReason::Label(_) => "invalid label".to_string(),
+    };
+
+    LabeledSpan::new(Some(text), start, end - start)
+}
+
+fn requirement_code(error: &RequirementParserError) -> &'static str {
+    match error {
+        RequirementParserError::Numeric {
+            error: NumericError::LeadingZero,
+            ..
+        } => "version_number::leading_zero",
+        RequirementParserError::Numeric {
+            error: NumericError::Overflow,
+            ..
+        } => "version_number::overflow",
+        _ => "version_number::requirement",
+    }
+}
+
+fn numeric_help(error: &NumericError) -> String {
+    match error {
+        NumericError::LeadingZero => "remove the leading zero".to_string(),
+        NumericError::Overflow => format!("a component may be at most {}", u64::MAX),
+    }
+}
+
+fn requirement_help(error: &RequirementParserError) -> Option<String> {
+    match error {
+        RequirementParserError::Numeric { error, .. } => Some(numeric_help(error)),
+        _ => None,
+    }
+}
+
+fn requirement_label(error: &RequirementParserError) -> Option<LabeledSpan> {
+    match *error {
+        RequirementParserError::Empty => None,
+        RequirementParserError::ExpectedNumericToken { at, got } => Some(LabeledSpan::new(
+            Some("expected 0-9".to_string()),
+            at,
+            usize::from(got.is_some()),
+        )),
+        RequirementParserError::ExpectedComma { at, .. } => {
+            Some(LabeledSpan::new(Some("expected ','".to_string()), at, 1))
+        }
+        RequirementParserError::ExpectedEndOfInput { at, .. } => Some(LabeledSpan::new(
+            Some("expected end of input".to_string()),
+            at,
+            1,
+        )),
+        RequirementParserError::UnexpectedWildcard { at } => {
+            Some(LabeledSpan::new(Some("wildcard".to_string()), at, 1))
+        }
+        RequirementParserError::Numeric { at, end, .. } => {
+            Some(LabeledSpan::new(Some("number".to_string()), at, end - at))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::original;
+    use crate::{BaseVersion, Version};
+
+    fn labels(diagnostic: &dyn Diagnostic) -> Vec<(Option<String>, usize, usize)> {
+        diagnostic
+            .labels()
+            .into_iter()
+            .flatten()
+            .map(|label| (label.label().map(String::from), label.offset(), label.len()))
+            .collect()
+    }
+
+    #[yare::parameterized(
+        leading_zero = { "1.02", "version_number::leading_zero", Some("remove the leading zero"), (Some("leading zero"), 2, 2) },
+        overflow = { "18446744073709551616.0", "version_number::overflow", Some("a component may be at most 18446744073709551615"), (Some("number too large"), 0, 20) },
+        label = { "1.2.3-beta", "version_number::unexpected_token", Some("pre-release labels and build metadata are not allowed here; a LabeledVersion accepts them"), (Some("expected 0-9 or EOI"), 5, 1) },
+        whitespace = { "1.2 ", "version_number::unexpected_token", Some("remove the whitespace"), (Some("expected 0-9, '.' or EOI"), 3, 1) },
+        major_only = { "1", "version_number::unexpected_token", Some("a version consists of two or three components, like `1.2` or `1.2.3`"), (Some("expected 0-9 or '.'"), 1, 0) },
+        non_ascii = { "1.2é", "version_number::unexpected_token", None, (Some("expected 0-9, '.' or EOI"), 3, 2) },
+    )]
+    fn parser_error(
+        input: &str,
+        code: &str,
+        help: Option<&str>,
+        label: (Option<&str>, usize, usize),
+    ) {
+        let err = Version::parse(input).unwrap_err();
+        let (text, offset, len) = label;
+
+        assert_eq!(err.code().unwrap().to_string(), code);
+        assert_eq!(err.help().map(|help| help.to_string()).as_deref(), help);
+        assert_eq!(labels(&err), vec![(text.map(String::from), offset, len)]);
+    }
+
+    #[test]
+    fn original_parser_error_has_source_code() {
+        let err = original::Parser::from_slice(b"1.02").parse().unwrap_err();
+
+        assert_eq!(
+            err.code().unwrap().to_string(),
+            "version_number::leading_zero"
+        );
+        assert_eq!(labels(&err), vec![(Some("leading zero".to_string()), 2, 2)]);
+
+        let source = err.source_code().unwrap();
+        let contents = source.read_span(&(2, 2).into(), 0, 0).unwrap();
+        assert_eq!(contents.data(), b"02");
+    }
+
+    #[test]
+    fn modular_parser_error() {
+        let err = crate::parsers::modular::Parser::from_slice(b"1.2.3~")
+            .parse_labeled()
+            .unwrap_err();
+
+        assert_eq!(
+            err.code().unwrap().to_string(),
+            "version_number::unexpected_token"
+        );
+        assert_eq!(
+            labels(&err),
+            vec![(Some("expected 0-9, '-', '+' or EOI".to_string()), 5, 1)]
+        );
+        assert!(err.source_code().is_none());
+    }
+
+    #[yare::parameterized(
+        empty = { "1.2.3-", "version_number::label::empty_identifier" },
+        invalid = { "1.2.3-a~", "version_number::label::invalid_character" },
+        leading_zero = { "1.2.3-01", "version_number::label::leading_zero" },
+    )]
+    fn label_error(input: &str, code: &str) {
+        let err = crate::LabeledVersion::parse(input).unwrap_err();
+
+        assert_eq!(err.code().unwrap().to_string(), code);
+        assert!(err.help().is_some());
+    }
+
+    #[yare::parameterized(
+        leading_zero = { ">=1.02", "version_number::leading_zero", Some("remove the leading zero"), (Some("number"), 4, 2) },
+        overflow = { "1.18446744073709551616, <2", "version_number::overflow", Some("a component may be at most 18446744073709551615"), (Some("number"), 2, 20) },
+        wildcard = { ">=1.*", "version_number::requirement", None, (Some("wildcard"), 4, 1) },
+    )]
+    fn requirement_error(
+        input: &str,
+        code: &str,
+        help: Option<&str>,
+        label: (Option<&str>, usize, usize),
+    ) {
+        let err = Error::from(input.parse::<crate::VersionReq>().unwrap_err());
+        let (text, offset, len) = label;
+
+        assert_eq!(err.code().unwrap().to_string(), code);
+        assert_eq!(err.help().map(|help| help.to_string()).as_deref(), help);
+        assert_eq!(labels(&err), vec![(text.map(String::from), offset, len)]);
+    }
+
+    #[test]
+    fn report_with_source_code() {
+        let input = "1.02";
+        let err = BaseVersion::parse(input).unwrap_err();
+        let report = miette::Report::new(err).with_source_code(input);
+
+        assert_eq!(
+            report.code().unwrap().to_string(),
+            "version_number::leading_zero"
+        );
+        assert!(report.source_code().is_some());
+    }
+}
@@ -1,4 +1,5 @@
 #![deny(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
 //! # version-number
 //!
@@ -17,42 +18,257 @@
 //! rejected by [`semver`].
 //!
 //! In addition [`Version`] does not accept extra labels such as build parameters, which are
-//! an extension of the [`semver`] version number itself.
+//! an extension of the [`semver`] version number itself. If you do need these labels, you may
+//! use the separate [`LabeledVersion`] type, which wraps a [`Version`] and adds a pre-release
+//! label and build metadata.
 //!
 //! In this crate, we call a two component `major.minor` version number a [`BaseVersion`], and
 //! we call a three component `major.minor.patch` version number a [`FullVersion`].
 //!
+//! Versions with another number of components, like the four component `1.2.3.4`, may be
+//! represented by a [`ComponentVersion`].
+//!
+//! To keep many versions in memory, a [`PackedBaseVersion`] or [`PackedFullVersion`] packs a
+//! version into a single `u64`, with a configurable number of bits for each component, such that
+//! the packed integer sorts like the version.
+//!
+//! ## Version literals
+//!
+//! The constructors of the version types are `const`, and [`Version::parse_const`],
+//! [`BaseVersion::parse_const`] and [`FullVersion::parse_const`] parse a version in a `const`
+//! context. The [`version!`] macro parses a literal like `version!("1.56")` at compile time, and
+//! fails the compilation if it is not a valid version.
+//!
+//! ## Semver
+//!
+//! When the `semver` feature is enabled, versions may be converted from and to the types of the
+//! [`semver` crate], and may be matched against a `semver::VersionReq`.
+//!
+//! ## Serde
+//!
+//! When the `serde` feature is enabled, [`Version`], [`BaseVersion`], [`FullVersion`] and
+//! [`Variant`] implement `Serialize` and `Deserialize`. Versions are serialized as a string,
+//! and may be deserialized from a string, a sequence of components like `[1, 2, 3]`, or a map
+//! of components like `{ "major": 1, "minor": 2, "patch": 3 }`.
+//!
+//! ## Cargo manifests
+//!
+//! When the `manifest` feature is enabled, the [`manifest`] module can be used to read the
+//! `package.rust-version` of a Cargo manifest as a [`Version`].
+//!
+//! ## Toolchains
+//!
+//! The [`toolchain`] module parses rustup toolchain names like `1.70.0` or `nightly-2023-05-01`,
+//! and the legacy `rust-toolchain` file. When the `toml` feature is enabled, `rust-toolchain.toml`
+//! files can be parsed as well.
+//!
+//! ## Rustc
+//!
+//! The [`rustc`] module parses the output of `rustc --version` and `rustc -vV` into a
+//! [`RustcVersionInfo`], which holds the release, channel, commit and LLVM version of a compiler.
+//!
+//! ## Diagnostics
+//!
+//! When the `miette` feature is enabled, [`Error`], [`ParserError`] and the errors of the
+//! original and modular parsers implement `miette::Diagnostic`, with a stable error code, a label
+//! which points at the offending span of the input, and a help text where possible.
+//!
+//! ## Compatibility
+//!
+//! The [`compat`] module classifies the change between two versions as a major, minor or patch
+//! change, and checks whether two versions are compatible by the rules of Cargo, where a change
+//! of the `minor` component of a `0.y.z` version, and of the `patch` component of a `0.0.z`
+//! version, is breaking.
+//!
+//! ## Scanning
+//!
+//! The [`scanner`] module finds the version numbers which occur in free text, such as
+//! `requires Rust 1.65 or newer`, with configurable rules for the bytes which may surround them.
+//!
+//! ## Combinators
+//!
+//! When the `nom` or `winnow` feature is enabled, the [`nom`] and [`winnow`] modules provide
+//! `component`, `base_version`, `full_version` and `version` combinators, to parse version
+//! numbers as part of a larger grammar. Both are also available without the `std` feature.
+//!
+//! ## Version requirements
+//!
+//! A [`VersionReq`] may be used to check whether a version satisfies a set of
+//! Cargo-style constraints, such as `>=1.56, <1.70`, `^1.2` or `~1.2`.
+//!
+//! ## Version sets
+//!
+//! A [`VersionSet`] is a set of three component versions, which may be created from a
+//! [`VersionReq`], a version, or a range of versions, and combined by union, intersection and
+//! complement. When the `pubgrub` feature is enabled, it implements `pubgrub::VersionSet`, so a
+//! [`FullVersion`] may be used as the version type of the `pubgrub` dependency resolver.
+//!
+//! ## no_std
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `no_std`, and does not
+//! require `alloc` either: [`Version`], [`BaseVersion`], [`FullVersion`], the modular parser and
+//! the error types, which only hold fixed-size data, remain available, and their `Display`
+//! implementations are written through `core::fmt`.
+//!
+//! The types and parsers which need to allocate, such as [`LabeledVersion`], [`VersionReq`],
+//! [`VersionSet`], the original and lenient parsers, and the rendering of error snippets, require
+//! the `std` feature, as do the `semver`, `serde`, `manifest`, `toml`, `miette` and `pubgrub`
+//! features.
+//!
 //! [`semver`]: https://semver.org/spec/v2.0.0.html
 //! [`Version`]: crate::Version
 //! [`BaseVersion`]: crate::BaseVersion
 //! [`FullVersion`]: crate::FullVersion
+//! [`ComponentVersion`]: crate::ComponentVersion
+//! [`version!`]: crate::version
+//! [`Version::parse_const`]: crate::Version::parse_const
+//! [`BaseVersion::parse_const`]: crate::BaseVersion::parse_const
+//! [`FullVersion::parse_const`]: crate::FullVersion::parse_const
+//! [`PackedBaseVersion`]: crate::PackedBaseVersion
+//! [`PackedFullVersion`]: crate::PackedFullVersion
+//! [`VersionReq`]: crate::VersionReq
+//! [`VersionSet`]: crate::VersionSet
+//! [`Variant`]: crate::Variant
+//! [`LabeledVersion`]: crate::LabeledVersion
+//! [`manifest`]: crate::manifest
+//! [`nom`]: crate::nom
+//! [`scanner`]: crate::scanner
+//! [`compat`]: crate::compat
+//! [`winnow`]: crate::winnow
+//! [`toolchain`]: crate::toolchain
+//! [`rustc`]: crate::rustc
+//! [`RustcVersionInfo`]: crate::rustc::RustcVersionInfo
+//! [`semver` crate]: https://crates.io/crates/semver
+
+#[cfg(not(feature = "std"))]
+mod features {
+    #[cfg(feature = "semver")]
+    compile_error!("The `semver` feature requires the `std` feature");
+    #[cfg(feature = "serde")]
+    compile_error!("The `serde` feature requires the `std` feature");
+    #[cfg(feature = "toml")]
+    compile_error!("The `toml` feature requires the `std` feature");
+    #[cfg(feature = "miette")]
+    compile_error!("The `miette` feature requires the `std` feature");
+    #[cfg(feature = "pubgrub")]
+    compile_error!("The `pubgrub` feature requires the `std` feature");
+}
 
-use std::fmt;
-use std::str::FromStr;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
 
-use crate::parsers::original;
+use crate::parsers::modular::{ModularParser, ModularParserError};
 
 pub use parsers::{BaseVersionParser, FullVersionParser, ParserError, VersionParser};
-pub use version::{BaseVersion, FullVersion};
+#[cfg(feature = "std")]
+pub use requirement::{Comparator, Op, VersionReq};
+#[cfg(feature = "std")]
+pub use set::VersionSet;
+pub use version::{
+    BaseVersion, BumpError, BumpMode, Component, ComponentVersion, FullVersion, PackError,
+    PackedBaseVersion, PackedFullVersion,
+};
+#[cfg(feature = "std")]
+pub use version::{BuildMetadata, LabeledVersion, Prerelease};
+
+#[cfg(feature = "semver")]
+pub use version::SemverConversionError;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::parse_version_literal;
+}
 
 /// This crate contains multiple parsers.
 ///
-/// In general, it's easiest to use the well tested [`parsers::original::Parser`], which is also used
-/// (currently) by [`Version::parse`].
+/// In general, it's easiest to use the well tested [`parsers::original::Parser`], or the
+/// [`parsers::modular::Parser`], which is used by [`Version::parse`], and which is also
+/// available without the `std` feature.
 pub mod parsers;
 
+/// Read the `rust-version` from a Cargo manifest.
+///
+/// Requires the `manifest` feature to be enabled.
+#[cfg(feature = "manifest")]
+pub mod manifest;
+
+#[cfg(feature = "nom")]
+pub mod nom;
+
+#[cfg(feature = "winnow")]
+pub mod winnow;
+
+pub mod scanner;
+
+pub mod compat;
+
+/// Parse the output of `rustc --version` and `rustc -vV`.
+#[cfg(feature = "std")]
+pub mod rustc;
+
+/// Parse Rust toolchain names, and `rust-toolchain` files.
+#[cfg(feature = "std")]
+pub mod toolchain;
+
+#[cfg(feature = "miette")]
+mod diagnostic;
+#[macro_use]
+mod macros;
+#[cfg(feature = "std")]
+mod requirement;
+#[cfg(feature = "std")]
+mod set;
+#[cfg(feature = "toml")]
+mod toml_value;
 mod version;
 
 /// Top level errors for version-numbers.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug)]
 pub enum Error {
     /// An error which specifies failure to parse a version number.
-    #[error(transparent)]
-    ParserError(#[from] ParserError),
+    ParserError(ParserError),
+
+    /// An error which specifies failure to parse a version requirement.
+    RequirementParserError(parsers::requirement::RequirementParserError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParserError(e) => fmt::Display::fmt(e, f),
+            Self::RequirementParserError(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<ParserError> for Error {
+    fn from(error: ParserError) -> Self {
+        Self::ParserError(error)
+    }
+}
+
+impl From<parsers::requirement::RequirementParserError> for Error {
+    fn from(error: parsers::requirement::RequirementParserError) -> Self {
+        Self::RequirementParserError(error)
+    }
 }
 
 /// A numbered version which is a two-component `major.minor` version number,
 /// or a three-component `major.minor.patch` version number.
+///
+/// # Ordering
+///
+/// Versions are ordered by their `major`, `minor` and `patch` components, in that order.
+/// A two-component [`Version::Base`] is ordered before any three-component [`Version::Full`]
+/// with the same `major` and `minor` components. As a result, `1.2` and `1.2.0` are not equal,
+/// which is consistent with the [`Eq`] and [`Hash`] implementations.
+///
+/// To compare versions with a different policy for two- and three-component versions,
+/// see [`Version::cmp_with`].
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum Version {
     /// A two-component `major.minor` version.
@@ -67,18 +283,27 @@ impl Version {
     ///
     /// Returns a [`Error::ParserError`] if it fails to parse.
     pub fn parse(input: &str) -> Result<Self, Error> {
-        original::Parser::from(input.as_bytes())
-            .parse()
-            .map_err(|e| Error::from(Into::<ParserError>::into(e)))
+        ModularParser.parse_version(input).map_err(Error::from)
+    }
+
+    /// Parse a two- or three-component version number from a given input, in a `const` context.
+    ///
+    /// Follows the same rules as [`Version::parse`], but returns the [`ModularParserError`] of
+    /// the modular parser if it fails to parse. See the [`version!`] macro to check a version
+    /// literal at compile time.
+    ///
+    /// [`ModularParserError`]: crate::parsers::modular::ModularParserError
+    pub const fn parse_const(input: &str) -> Result<Self, ModularParserError> {
+        parsers::modular::constant::parse_version(input.as_bytes())
     }
 
     /// Create a new two-component `major.minor` version number.
-    pub fn new_base_version(major: u64, minor: u64) -> Self {
+    pub const fn new_base_version(major: u64, minor: u64) -> Self {
         Self::Base(BaseVersion { major, minor })
     }
 
     /// Create a new three-component `major.minor.patch` version number.
-    pub fn new_full_version(major: u64, minor: u64, patch: u64) -> Self {
+    pub const fn new_full_version(major: u64, minor: u64, patch: u64) -> Self {
         Self::Full(FullVersion {
             major,
             minor,
@@ -121,6 +346,151 @@ impl Version {
         }
     }
 
+    /// Compare two versions, where `policy` determines how a two-component [`Version::Base`]
+    /// compares against a three-component [`Version::Full`] with the same `major` and `minor`
+    /// components.
+    ///
+    /// The [`Ord`] implementation of [`Version`] uses [`ComparisonPolicy::BaseBeforeFull`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use version_number::{ComparisonPolicy, Version};
+    ///
+    /// let base = Version::new_base_version(1, 2);
+    /// let full = Version::new_full_version(1, 2, 0);
+    ///
+    /// assert_eq!(base.cmp_with(&full, ComparisonPolicy::BaseEqualsPatchZero), Ordering::Equal);
+    /// assert_eq!(base.cmp_with(&full, ComparisonPolicy::BaseBeforeFull), Ordering::Less);
+    /// assert_eq!(base.cmp_with(&full, ComparisonPolicy::BaseMatchesAnyPatch), Ordering::Equal);
+    /// ```
+    pub fn cmp_with(&self, other: &Self, policy: ComparisonPolicy) -> Ordering {
+        let lhs = (self.major(), self.minor());
+        let rhs = (other.major(), other.minor());
+
+        lhs.cmp(&rhs)
+            .then_with(|| match (self.patch(), other.patch()) {
+                (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+                (None, None) => Ordering::Equal,
+                (lhs, rhs) => match policy {
+                    ComparisonPolicy::BaseEqualsPatchZero => {
+                        lhs.unwrap_or_default().cmp(&rhs.unwrap_or_default())
+                    }
+                    ComparisonPolicy::BaseBeforeFull => lhs.cmp(&rhs),
+                    ComparisonPolicy::BaseMatchesAnyPatch => Ordering::Equal,
+                },
+            })
+    }
+
+    /// Increment the `major` component, and reset the `minor` and `patch` (if any) components to `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `major` component equals `u64::MAX`. See [`Version::checked_bump_major`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::Version;
+    ///
+    /// assert_eq!(Version::new_base_version(1, 2).bump_major(), Version::new_base_version(2, 0));
+    /// assert_eq!(Version::new_full_version(1, 2, 3).bump_major(), Version::new_full_version(2, 0, 0));
+    /// ```
+    pub fn bump_major(self) -> Self {
+        self.checked_bump_major()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `minor` component, and reset the `patch` component (if any) to `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `minor` component equals `u64::MAX`. See [`Version::checked_bump_minor`]
+    /// for a non-panicking alternative.
+    pub fn bump_minor(self) -> Self {
+        self.checked_bump_minor()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `patch` component.
+    ///
+    /// A two-component [`Version::Base`] is considered to have a `patch` component of `0`, and
+    /// is bumped to a three-component [`Version::Full`] with a `patch` component of `1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `patch` component equals `u64::MAX`. See [`Version::checked_bump_patch`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::Version;
+    ///
+    /// assert_eq!(Version::new_base_version(1, 2).bump_patch(), Version::new_full_version(1, 2, 1));
+    /// assert_eq!(Version::new_full_version(1, 2, 3).bump_patch(), Version::new_full_version(1, 2, 4));
+    /// ```
+    pub fn bump_patch(self) -> Self {
+        self.checked_bump_patch()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Bump the version for a backwards incompatible change. The [`BumpMode`] determines
+    /// whether the `major`, or for versions with a `major` component of `0` the `minor`, component
+    /// is incremented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the incremented component equals `u64::MAX`. See
+    /// [`Version::checked_bump_breaking`] for a non-panicking alternative.
+    pub fn bump_breaking(self, mode: BumpMode) -> Self {
+        self.checked_bump_breaking(mode)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `major` component, and reset the `minor` and `patch` (if any) components to `0`.
+    ///
+    /// Returns a [`BumpError`] if the `major` component would overflow.
+    pub fn checked_bump_major(self) -> Result<Self, BumpError> {
+        match self {
+            Self::Base(base) => base.checked_bump_major().map(Self::Base),
+            Self::Full(full) => full.checked_bump_major().map(Self::Full),
+        }
+    }
+
+    /// Increment the `minor` component, and reset the `patch` component (if any) to `0`.
+    ///
+    /// Returns a [`BumpError`] if the `minor` component would overflow.
+    pub fn checked_bump_minor(self) -> Result<Self, BumpError> {
+        match self {
+            Self::Base(base) => base.checked_bump_minor().map(Self::Base),
+            Self::Full(full) => full.checked_bump_minor().map(Self::Full),
+        }
+    }
+
+    /// Increment the `patch` component, as described by [`Version::bump_patch`].
+    ///
+    /// Returns a [`BumpError`] if the `patch` component would overflow.
+    pub fn checked_bump_patch(self) -> Result<Self, BumpError> {
+        match self {
+            Self::Base(base) => Ok(Self::Full(base.bump_patch())),
+            Self::Full(full) => full.checked_bump_patch().map(Self::Full),
+        }
+    }
+
+    /// Bump the version for a backwards incompatible change, as described by
+    /// [`Version::bump_breaking`].
+    ///
+    /// Returns a [`BumpError`] if the incremented component would overflow.
+    pub fn checked_bump_breaking(self, mode: BumpMode) -> Result<Self, BumpError> {
+        match self {
+            Self::Base(base) => base.checked_bump_breaking(mode).map(Self::Base),
+            Self::Full(full) => full.checked_bump_breaking(mode).map(Self::Full),
+        }
+    }
+
     /// Check of which variant `self` is.
     pub fn is(&self, variant: Variant) -> bool {
         match self {
@@ -239,13 +609,23 @@ impl Version {
     }
 }
 
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_with(other, ComparisonPolicy::BaseBeforeFull)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl FromStr for Version {
     type Err = Error;
 
     fn from_str(input: &str) -> Result<Self, Error> {
-        original::Parser::from_slice(input.as_bytes())
-            .parse()
-            .map_err(|e| Error::from(Into::<ParserError>::into(e)))
+        Self::parse(input)
     }
 }
 
@@ -270,6 +650,18 @@ impl From<(u64, u64, u64)> for Version {
     }
 }
 
+impl From<BaseVersion> for Version {
+    fn from(version: BaseVersion) -> Self {
+        Self::Base(version)
+    }
+}
+
+impl From<FullVersion> for Version {
+    fn from(version: FullVersion) -> Self {
+        Self::Full(version)
+    }
+}
+
 /// Type used to indicate which variant of a [`Version`] is used.
 /// The options are [`Base`] for [`Version::Base`], and [`Full`] for [`Version::Full`].
 ///
@@ -279,6 +671,7 @@ impl From<(u64, u64, u64)> for Version {
 /// [`Full`]: crate::Variant::Full
 /// [`Version::Full`]: crate::Version::Full
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Variant {
     /// Indicates a [`Version::Base`] is used.
     ///
@@ -290,6 +683,45 @@ pub enum Variant {
     Full,
 }
 
+/// Policy which determines how a two-component [`Version::Base`] compares against a
+/// three-component [`Version::Full`], with the same `major` and `minor` components.
+///
+/// Used by [`Version::cmp_with`].
+///
+/// [`Version::Base`]: crate::Version::Base
+/// [`Version::Full`]: crate::Version::Full
+/// [`Version::cmp_with`]: crate::Version::cmp_with
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ComparisonPolicy {
+    /// A base version `major.minor` is equal to the full version `major.minor.0`.
+    ///
+    /// For example, `1.2` equals `1.2.0`, and is less than `1.2.1`.
+    BaseEqualsPatchZero,
+    /// A base version `major.minor` sorts before any full version `major.minor.patch`.
+    ///
+    /// For example, `1.2` is less than both `1.2.0` and `1.2.1`.
+    ///
+    /// This is the policy used by the [`Ord`] implementation of [`Version`], since it is the only
+    /// policy which is consistent with its [`Eq`] implementation.
+    ///
+    /// [`Version`]: crate::Version
+    BaseBeforeFull,
+    /// A base version `major.minor` is a wildcard which is equal to any full version
+    /// `major.minor.patch`.
+    ///
+    /// For example, `1.2` equals both `1.2.0` and `1.2.1`.
+    ///
+    /// **NB:** This comparison is not transitive, and should not be used to sort versions: `1.2.0`
+    /// and `1.2.1` both equal `1.2`, but are not equal to each other.
+    BaseMatchesAnyPatch,
+}
+
+impl Default for ComparisonPolicy {
+    fn default() -> Self {
+        Self::BaseBeforeFull
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{BaseVersion, FullVersion, Variant, Version};
@@ -358,3 +790,109 @@ mod tests {
         assert_eq!(mapped.patch().unwrap(), 999);
     }
 }
+
+#[cfg(test)]
+mod bump_tests {
+    use crate::{BumpError, BumpMode, Component, Version};
+
+    #[yare::parameterized(
+        base_major = { Version::new_base_version(1, 2).bump_major(), Version::new_base_version(2, 0) },
+        full_major = { Version::new_full_version(1, 2, 3).bump_major(), Version::new_full_version(2, 0, 0) },
+        base_minor = { Version::new_base_version(1, 2).bump_minor(), Version::new_base_version(1, 3) },
+        full_minor = { Version::new_full_version(1, 2, 3).bump_minor(), Version::new_full_version(1, 3, 0) },
+        base_patch = { Version::new_base_version(1, 2).bump_patch(), Version::new_full_version(1, 2, 1) },
+        full_patch = { Version::new_full_version(1, 2, 3).bump_patch(), Version::new_full_version(1, 2, 4) },
+        base_breaking = { Version::new_base_version(0, 2).bump_breaking(BumpMode::InitialDevelopment), Version::new_base_version(0, 3) },
+        full_breaking = { Version::new_full_version(0, 2, 3).bump_breaking(BumpMode::Standard), Version::new_full_version(1, 0, 0) },
+    )]
+    fn bump(bumped: Version, expected: Version) {
+        assert_eq!(bumped, expected);
+    }
+
+    #[yare::parameterized(
+        base_major = { Version::new_base_version(u64::MAX, 0).checked_bump_major(), Component::Major },
+        full_minor = { Version::new_full_version(0, u64::MAX, 0).checked_bump_minor(), Component::Minor },
+        full_patch = { Version::new_full_version(0, 0, u64::MAX).checked_bump_patch(), Component::Patch },
+    )]
+    fn checked_overflow(result: Result<Version, BumpError>, component: Component) {
+        assert_eq!(result.unwrap_err(), BumpError::Overflow { component });
+    }
+}
+
+#[cfg(test)]
+mod ord_tests {
+    use crate::{ComparisonPolicy, Version};
+    use std::cmp::Ordering;
+    use std::collections::BTreeMap;
+
+    #[yare::parameterized(
+        base_major = { Version::new_base_version(1, 9), Version::new_base_version(2, 0) },
+        base_minor = { Version::new_base_version(1, 1), Version::new_base_version(1, 2) },
+        full_patch = { Version::new_full_version(1, 1, 1), Version::new_full_version(1, 1, 2) },
+        base_before_full = { Version::new_base_version(1, 2), Version::new_full_version(1, 2, 0) },
+        full_before_base = { Version::new_full_version(1, 1, 99), Version::new_base_version(1, 2) },
+    )]
+    fn less(lhs: Version, rhs: Version) {
+        assert_eq!(lhs.cmp(&rhs), Ordering::Less);
+        assert_eq!(rhs.cmp(&lhs), Ordering::Greater);
+        assert_eq!(lhs.partial_cmp(&rhs), Some(Ordering::Less));
+    }
+
+    #[yare::parameterized(
+        base = { Version::new_base_version(1, 2) },
+        full = { Version::new_full_version(1, 2, 3) },
+    )]
+    fn equals(version: Version) {
+        assert_eq!(version.cmp(&version.clone()), Ordering::Equal);
+    }
+
+    #[test]
+    fn sort() {
+        let mut versions = vec![
+            Version::new_full_version(1, 2, 1),
+            Version::new_base_version(1, 3),
+            Version::new_full_version(1, 2, 0),
+            Version::new_base_version(1, 2),
+        ];
+
+        versions.sort();
+
+        assert_eq!(
+            versions,
+            vec![
+                Version::new_base_version(1, 2),
+                Version::new_full_version(1, 2, 0),
+                Version::new_full_version(1, 2, 1),
+                Version::new_base_version(1, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn btree_map_key() {
+        let mut map = BTreeMap::new();
+        map.insert(Version::new_full_version(1, 2, 0), "full");
+        map.insert(Version::new_base_version(1, 2), "base");
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            vec!["base", "full"]
+        );
+    }
+
+    #[yare::parameterized(
+        base_zero = { ComparisonPolicy::BaseEqualsPatchZero, Version::new_base_version(1, 2), Version::new_full_version(1, 2, 0), Ordering::Equal },
+        base_one = { ComparisonPolicy::BaseEqualsPatchZero, Version::new_base_version(1, 2), Version::new_full_version(1, 2, 1), Ordering::Less },
+        full_base = { ComparisonPolicy::BaseEqualsPatchZero, Version::new_full_version(1, 2, 1), Version::new_base_version(1, 2), Ordering::Greater },
+        before_zero = { ComparisonPolicy::BaseBeforeFull, Version::new_base_version(1, 2), Version::new_full_version(1, 2, 0), Ordering::Less },
+        before_full_base = { ComparisonPolicy::BaseBeforeFull, Version::new_full_version(1, 2, 0), Version::new_base_version(1, 2), Ordering::Greater },
+        any_zero = { ComparisonPolicy::BaseMatchesAnyPatch, Version::new_base_version(1, 2), Version::new_full_version(1, 2, 0), Ordering::Equal },
+        any_max = { ComparisonPolicy::BaseMatchesAnyPatch, Version::new_full_version(1, 2, u64::MAX), Version::new_base_version(1, 2), Ordering::Equal },
+        any_minor = { ComparisonPolicy::BaseMatchesAnyPatch, Version::new_base_version(1, 2), Version::new_full_version(1, 3, 0), Ordering::Less },
+        full_full = { ComparisonPolicy::BaseMatchesAnyPatch, Version::new_full_version(1, 2, 0), Version::new_full_version(1, 2, 1), Ordering::Less },
+    )]
+    fn cmp_with(policy: ComparisonPolicy, lhs: Version, rhs: Version, expected: Ordering) {
+        assert_eq!(lhs.cmp_with(&rhs, policy), expected);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::parsers::modular::{ModularParserError, NumberError};
+use crate::Version;
+
+/// Create a [`Version`] from a string literal, which is parsed at compile time.
+///
+/// The literal must be a two component `major.minor`, or a three component `major.minor.patch`
+/// version, as accepted by [`Version::parse`], or compilation fails. Since the version is parsed
+/// at compile time, the macro may be used to define `const` and `static` items.
+///
+/// # Example
+///
+/// ```
+/// use version_number::{version, Version};
+///
+/// const MSRV: Version = version!("1.56");
+/// static LATEST: Version = version!("1.70.0");
+///
+/// assert_eq!(MSRV, Version::new_base_version(1, 56));
+/// assert_eq!(LATEST, Version::new_full_version(1, 70, 0));
+/// ```
+///
+/// An invalid literal, like one with a leading zero, fails to compile:
+///
+/// ```compile_fail
+/// use version_number::{version, Version};
+///
+/// const MSRV: Version = version!("1.056");
+/// ```
+///
+/// [`Version`]: crate::Version
+/// [`Version::parse`]: crate::Version::parse
+#[macro_export]
+macro_rules! version {
+    ($input:literal) => {{
+        const VERSION: $crate::Version = $crate::__private::parse_version_literal($input);
+        VERSION
+    }};
+}
+
+/// Parse the literal of the [`version!`] macro, or fail the compilation, since the macro
+/// evaluates this function in a `const` item.
+#[doc(hidden)]
+pub const fn parse_version_literal(input: &str) -> Version {
+    match Version::parse_const(input) {
+        Ok(version) => version,
+        Err(ModularParserError::NumberError {
+            error: NumberError::LeadingZero,
+            ..
+        }) => panic!("invalid version literal: a component has a leading zero"),
+        Err(ModularParserError::NumberError {
+            error: NumberError::Overflow,
+            ..
+        }) => panic!("invalid version literal: a component does not fit in a u64"),
+        Err(_) => panic!(
+            "invalid version literal: expected a two or three component version, like \"1.56\" or \"1.56.0\""
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_version_literal;
+    use crate::Version;
+
+    const BASE: Version = version!("1.56");
+    static FULL: Version = version!("1.70.0");
+
+    #[test]
+    fn const_item() {
+        assert_eq!(BASE, Version::new_base_version(1, 56));
+    }
+
+    #[test]
+    fn static_item() {
+        assert_eq!(FULL, Version::new_full_version(1, 70, 0));
+    }
+
+    #[test]
+    fn expression() {
+        assert_eq!(
+            version!("18446744073709551615.0.0"),
+            Version::new_full_version(u64::MAX, 0, 0)
+        );
+    }
+
+    #[yare::parameterized(
+        leading_zero = { "1.056", "invalid version literal: a component has a leading zero" },
+        overflow = { "18446744073709551616.0", "invalid version literal: a component does not fit in a u64" },
+        major_only = { "1", "invalid version literal: expected a two or three component version" },
+    )]
+    fn invalid(input: &str, message: &str) {
+        let err = std::panic::catch_unwind(|| parse_version_literal(input)).unwrap_err();
+        let panic = err.downcast_ref::<&str>().unwrap();
+
+        assert!(panic.starts_with(message));
+    }
+}
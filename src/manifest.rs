@@ -0,0 +1,392 @@
+//! Read the `rust-version` of a package from its Cargo manifest, i.e. its `Cargo.toml`.
+//!
+//! Requires the `manifest` feature to be enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::manifest;
+//! use version_number::Version;
+//!
+//! let manifest = r#"
+//! [package]
+//! name = "example"
+//! version = "0.1.0"
+//! rust-version = "1.56"
+//! "#;
+//!
+//! let version = manifest::rust_version_from_str(manifest).unwrap();
+//!
+//! assert_eq!(version, Version::new_base_version(1, 56));
+//! ```
+
+use crate::parsers::original::OriginalParser;
+use crate::toml_value::indefinite_article;
+use crate::{ParserError, Version, VersionParser};
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub use self::workspace::{workspace_rust_versions, WorkspaceRustVersions};
+
+mod workspace;
+
+/// Read the `package.rust-version` of the Cargo manifest at the given `path`.
+///
+/// Returns a [`ManifestError`] if the manifest can't be read, is not valid TOML, does not
+/// contain a `package.rust-version` key, or if its value is not a valid version.
+///
+/// A `rust-version` which is inherited from the workspace, i.e. `rust-version.workspace = true`,
+/// can't be resolved from a single manifest. Use [`workspace_rust_versions`] instead.
+pub fn rust_version_from_path<P: AsRef<Path>>(path: P) -> Result<Version, ManifestError> {
+    let path = path.as_ref();
+
+    read(path)
+        .and_then(|contents| rust_version_from_str(&contents))
+        .map_err(|error| error.with_path(path))
+}
+
+/// Read the `package.rust-version` of the given Cargo manifest contents.
+///
+/// Returns a [`ManifestError`] if the manifest is not valid TOML, does not contain a
+/// `package.rust-version` key, or if its value is not a valid version.
+///
+/// A `rust-version` which is inherited from the workspace, i.e. `rust-version.workspace = true`,
+/// can't be resolved from a single manifest. Use [`workspace_rust_versions`] instead.
+pub fn rust_version_from_str(contents: &str) -> Result<Version, ManifestError> {
+    let manifest = Manifest::parse(contents)?;
+
+    let value = manifest
+        .package
+        .and_then(|package| package.rust_version)
+        .ok_or_else(|| ManifestError::new(Location::default(), ErrorReason::MissingKey))?;
+
+    let line = line_of(contents, value.span().start);
+
+    rust_version(value.into_inner(), line)?.ok_or_else(|| {
+        ManifestError::new(Location::at_line(line), ErrorReason::InheritedFromWorkspace)
+    })
+}
+
+fn read(path: &Path) -> Result<String, ManifestError> {
+    std::fs::read_to_string(path)
+        .map_err(|error| ManifestError::new(Location::default(), ErrorReason::Io(error)))
+}
+
+/// Interpret the value of a `rust-version` key, found at the given `line`.
+///
+/// Returns `None` if the value is inherited from the workspace.
+fn rust_version(value: toml::Value, line: usize) -> Result<Option<Version>, ManifestError> {
+    match value {
+        toml::Value::String(version) => {
+            OriginalParser
+                .parse_version(version)
+                .map(Some)
+                .map_err(|error| {
+                    ManifestError::new(Location::at_line(line), ErrorReason::ParserError(error))
+                })
+        }
+        toml::Value::Table(ref table)
+            if table.len() == 1
+                && table.get("workspace").and_then(toml::Value::as_bool) == Some(true) =>
+        {
+            Ok(None)
+        }
+        other => Err(ManifestError::new(
+            Location::at_line(line),
+            ErrorReason::NotAString {
+                found: other.type_str(),
+            },
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    package: Option<Package>,
+    workspace: Option<Workspace>,
+}
+
+impl Manifest {
+    fn parse(contents: &str) -> Result<Self, ManifestError> {
+        toml::from_str(contents)
+            .map_err(|error| ManifestError::new(Location::default(), ErrorReason::Toml(error)))
+    }
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: Option<String>,
+    // Requires `toml` 0.9, since 0.8 fails to deserialize a `Spanned` value which is defined by
+    // dotted keys, like `rust-version.workspace = true`.
+    #[serde(rename = "rust-version")]
+    rust_version: Option<toml::Spanned<toml::Value>>,
+}
+
+#[derive(Deserialize)]
+struct Workspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    package: Option<WorkspacePackage>,
+}
+
+#[derive(Deserialize)]
+struct WorkspacePackage {
+    #[serde(rename = "rust-version")]
+    rust_version: Option<toml::Spanned<toml::Value>>,
+}
+
+fn line_of(contents: &str, offset: usize) -> usize {
+    contents.as_bytes()[..offset]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+/// The top-level error type for reading the `rust-version` from a Cargo manifest.
+#[derive(Debug, thiserror::Error)]
+#[error("Unable to read the rust-version from {location}: {reason}")]
+pub struct ManifestError {
+    location: Location,
+    reason: Box<ErrorReason>,
+}
+
+impl ManifestError {
+    fn new(location: Location, reason: ErrorReason) -> Self {
+        Self {
+            location,
+            reason: Box::new(reason),
+        }
+    }
+
+    fn with_path(mut self, path: &Path) -> Self {
+        self.location.path = Some(path.to_path_buf());
+        self
+    }
+
+    /// The reason why the `rust-version` could not be read.
+    pub fn reason(&self) -> &ErrorReason {
+        &self.reason
+    }
+
+    /// The path of the manifest, if the manifest was read from a file.
+    pub fn path(&self) -> Option<&Path> {
+        self.location.path.as_deref()
+    }
+
+    /// The line, starting at 1, of the `rust-version` value in the manifest, if the key was found.
+    pub fn line(&self) -> Option<usize> {
+        self.location.line
+    }
+}
+
+#[derive(Debug, Default)]
+struct Location {
+    path: Option<PathBuf>,
+    line: Option<usize>,
+}
+
+impl Location {
+    fn at_line(line: usize) -> Self {
+        Self {
+            path: None,
+            line: Some(line),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.line) {
+            (Some(path), Some(line)) => write!(f, "'{}:{}'", path.display(), line),
+            (Some(path), None) => write!(f, "'{}'", path.display()),
+            (None, Some(line)) => write!(f, "manifest at line {}", line),
+            (None, None) => f.write_str("manifest"),
+        }
+    }
+}
+
+/// Reasons for why the `rust-version` could not be read from a Cargo manifest.
+#[derive(Debug, thiserror::Error)]
+pub enum ErrorReason {
+    /// The manifest file could not be read.
+    #[error("Unable to read the manifest: {0}")]
+    Io(#[source] std::io::Error),
+
+    /// The manifest is not a valid TOML document, or its `package` key is not a table.
+    #[error("Unable to parse the manifest: {0}")]
+    Toml(#[source] toml::de::Error),
+
+    /// The manifest does not have a `package.rust-version` key.
+    #[error("The 'package.rust-version' key is missing")]
+    MissingKey,
+
+    /// The value of the `package.rust-version` key is not a string.
+    #[error(
+        "Expected the 'package.rust-version' value to be a string, but got {} {found}",
+        indefinite_article(.found)
+    )]
+    NotAString {
+        /// The TOML type of the value found instead.
+        found: &'static str,
+    },
+
+    /// The value of the `package.rust-version` key is not a valid version.
+    #[error(transparent)]
+    ParserError(ParserError),
+
+    /// The `package.rust-version` is inherited from the workspace, which can't be resolved
+    /// from a single manifest.
+    #[error("The 'package.rust-version' is inherited from the workspace, which requires resolving the workspace")]
+    InheritedFromWorkspace,
+
+    /// The manifest at the workspace root does not have a `workspace` table.
+    #[error("The manifest is not a workspace root, since the 'workspace' table is missing")]
+    NotAWorkspace,
+
+    /// The manifest of a workspace member does not have a `package.name` key.
+    #[error("The 'package.name' key is missing")]
+    MissingName,
+
+    /// The `package.rust-version` of a workspace member is inherited from the workspace, but the
+    /// workspace root does not have a `workspace.package.rust-version` key.
+    #[error("The 'package.rust-version' is inherited from the workspace, but the 'workspace.package.rust-version' key is missing")]
+    MissingWorkspaceKey,
+
+    /// Multiple workspace members have the same package name.
+    #[error("The package name '{name}' is used by more than one workspace member")]
+    DuplicatePackage {
+        /// The package name which is used more than once.
+        name: String,
+    },
+
+    /// A `workspace.members` entry without glob syntax does not match an existing path.
+    #[error("The workspace member '{member}' does not exist")]
+    MissingMember {
+        /// The `workspace.members` entry, relative to the workspace root.
+        member: String,
+    },
+
+    /// A `workspace.members` or `workspace.exclude` entry is not a valid glob pattern.
+    #[error("Invalid glob pattern in the workspace 'members' or 'exclude': {0}")]
+    Glob(#[source] glob::PatternError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::NumericError;
+
+    #[yare::parameterized(
+        base = { "[package]\nrust-version = \"1.56\"", Version::new_base_version(1, 56) },
+        full = { "[package]\nrust-version = \"1.70.1\"", Version::new_full_version(1, 70, 1) },
+        dotted_key = { "package.rust-version = \"1.56\"", Version::new_base_version(1, 56) },
+        inline_table = { "package = { name = \"a\", rust-version = \"1.56\" }", Version::new_base_version(1, 56) },
+    )]
+    fn from_str(contents: &str, expected: Version) {
+        let version = rust_version_from_str(contents).unwrap();
+
+        assert_eq!(version, expected);
+    }
+
+    #[test]
+    fn from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Cargo.toml");
+        std::fs::write(&path, "[package]\nname = \"a\"\nrust-version = \"1.56\"").unwrap();
+
+        let version = rust_version_from_path(&path).unwrap();
+
+        assert_eq!(version, Version::new_base_version(1, 56));
+    }
+
+    #[yare::parameterized(
+        no_package = { "[workspace]\nmembers = []" },
+        no_key = { "[package]\nname = \"a\"" },
+        misspelled_key = { "[package]\nrust_version = \"1.56\"" },
+    )]
+    fn missing_key(contents: &str) {
+        let err = rust_version_from_str(contents).unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::MissingKey));
+        assert_eq!(err.line(), None);
+    }
+
+    #[yare::parameterized(
+        integer = { "[package]\nrust-version = 1", "integer" },
+        float = { "[package]\n\nrust-version = 1.56", "float" },
+        table = { "[package]\nname = \"a\"\nrust-version = { workspace = false }", "table" },
+    )]
+    fn not_a_string(contents: &str, expected: &str) {
+        let err = rust_version_from_str(contents).unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::NotAString { found } if *found == expected));
+        assert_eq!(err.line(), Some(contents.lines().count()));
+    }
+
+    #[yare::parameterized(
+        integer = { "[package]\nrust-version = 1", "but got an integer" },
+        array = { "[package]\nrust-version = []", "but got an array" },
+        float = { "[package]\nrust-version = 1.56", "but got a float" },
+        boolean = { "[package]\nrust-version = true", "but got a boolean" },
+    )]
+    fn not_a_string_message(contents: &str, expected: &str) {
+        let err = rust_version_from_str(contents).unwrap_err();
+
+        assert!(err.to_string().ends_with(expected));
+    }
+
+    #[test]
+    fn parser_error() {
+        let err =
+            rust_version_from_str("[package]\nname = \"a\"\nrust-version = \"1.056\"").unwrap_err();
+
+        assert!(matches!(
+            err.reason(),
+            ErrorReason::ParserError(error) if *error.reason() == NumericError::LeadingZero.into()
+        ));
+        assert_eq!(err.line(), Some(3));
+    }
+
+    #[yare::parameterized(
+        dotted_key = { "[package]\nrust-version.workspace = true" },
+        inline_table = { "[package]\nrust-version = { workspace = true }" },
+    )]
+    fn inherited_from_workspace(contents: &str) {
+        let err = rust_version_from_str(contents).unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::InheritedFromWorkspace));
+        assert_eq!(err.line(), Some(2));
+    }
+
+    #[test]
+    fn invalid_toml() {
+        let err = rust_version_from_str("[package").unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::Toml(_)));
+    }
+
+    #[test]
+    fn io_error_includes_path() {
+        let err = rust_version_from_path("does/not/exist/Cargo.toml").unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::Io(_)));
+        assert_eq!(err.path(), Some(Path::new("does/not/exist/Cargo.toml")));
+        assert!(err.to_string().contains("'does/not/exist/Cargo.toml'"));
+    }
+
+    #[test]
+    fn display_includes_location() {
+        let err = rust_version_from_str("[package]\nrust-version = 1")
+            .map_err(|err| err.with_path(Path::new("Cargo.toml")))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unable to read the rust-version from 'Cargo.toml:2': Expected the 'package.rust-version' value to be a string, but got an integer"
+        );
+    }
+}
@@ -0,0 +1,552 @@
+use super::{line_of, read, rust_version, ErrorReason, Location, Manifest, ManifestError};
+use crate::Version;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// The `rust-version` of each package in a Cargo workspace, as resolved by
+/// [`workspace_rust_versions`].
+#[derive(Debug, Default)]
+pub struct WorkspaceRustVersions {
+    /// The `rust-version` of each package which could be resolved, by package name, or `None` if
+    /// the package does not specify a `rust-version`.
+    pub packages: BTreeMap<String, Option<Version>>,
+    /// An error for each member whose `rust-version` could not be resolved, which includes the
+    /// path of the manifest of the member, and for each path matched by a `workspace.members` or
+    /// `workspace.exclude` glob which could not be read, which includes that path.
+    pub errors: Vec<ManifestError>,
+}
+
+/// Resolve the `package.rust-version` of each package in the Cargo workspace at the given `root`.
+///
+/// The `root` may either be the directory of the workspace, or the path to its `Cargo.toml`.
+///
+/// The `workspace.members` and `workspace.exclude` globs are expanded relative to the workspace
+/// root, where a member is excluded when it is located within an excluded path. When the root
+/// manifest also has a `package` table, the root package is included as well.
+///
+/// A `rust-version` which is inherited from the workspace, i.e. `rust-version.workspace = true`,
+/// is resolved to the `workspace.package.rust-version` of the root manifest.
+///
+/// Each member is resolved independently: a member which does not specify a `rust-version` is
+/// included with `None`, and a member which can't be resolved, for example because its manifest
+/// is invalid, is reported in [`WorkspaceRustVersions::errors`], while the other members are
+/// still resolved. The same applies to a path matched by a glob which can't be read.
+///
+/// Returns a [`ManifestError`], which includes the path of the root manifest, if the root manifest
+/// can't be read, is not a workspace root, has an invalid `workspace.package.rust-version`, or if
+/// its `workspace.members` can't be expanded, for example because a member does not exist.
+///
+/// # Example
+///
+/// ```no_run
+/// use version_number::manifest;
+///
+/// let resolved = manifest::workspace_rust_versions("path/to/workspace").unwrap();
+///
+/// for (package, version) in &resolved.packages {
+///     match version {
+///         Some(version) => println!("{}: {}", package, version),
+///         None => println!("{}: no rust-version", package),
+///     }
+/// }
+///
+/// for error in &resolved.errors {
+///     eprintln!("{}", error);
+/// }
+/// ```
+///
+/// [`ManifestError`]: super::ManifestError
+pub fn workspace_rust_versions<P: AsRef<Path>>(
+    root: P,
+) -> Result<WorkspaceRustVersions, ManifestError> {
+    let root = root.as_ref();
+
+    let (root_dir, root_manifest) = if root.is_dir() {
+        (root.to_path_buf(), root.join("Cargo.toml"))
+    } else {
+        let dir = root.parent().unwrap_or_else(|| Path::new(""));
+        (dir.to_path_buf(), root.to_path_buf())
+    };
+
+    let contents = read(&root_manifest).map_err(|error| error.with_path(&root_manifest))?;
+    let manifest = Manifest::parse(&contents).map_err(|error| error.with_path(&root_manifest))?;
+
+    let workspace = manifest.workspace.ok_or_else(|| {
+        ManifestError::new(Location::default(), ErrorReason::NotAWorkspace)
+            .with_path(&root_manifest)
+    })?;
+
+    let inherited = match workspace.package.and_then(|package| package.rust_version) {
+        Some(value) => {
+            let line = line_of(&contents, value.span().start);
+
+            // Inheriting from the workspace is not valid within the workspace itself, so reject
+            // it as if it were any other non-string value.
+            let version = rust_version(value.into_inner(), line)
+                .and_then(|version| {
+                    version.ok_or_else(|| {
+                        ManifestError::new(
+                            Location::at_line(line),
+                            ErrorReason::NotAString { found: "table" },
+                        )
+                    })
+                })
+                .map_err(|error| error.with_path(&root_manifest))?;
+
+            Some(version)
+        }
+        None => None,
+    };
+
+    let mut resolved = WorkspaceRustVersions::default();
+
+    let excluded = expand_globs(&root_dir, &workspace.exclude, false, &mut resolved.errors)
+        .map_err(|error| error.with_path(&root_manifest))?;
+
+    let mut members = expand_globs(&root_dir, &workspace.members, true, &mut resolved.errors)
+        .map_err(|error| error.with_path(&root_manifest))?
+        .into_iter()
+        .filter(|member| member.is_dir())
+        .filter(|member| !excluded.iter().any(|path| member.starts_with(path)))
+        .collect::<BTreeSet<_>>();
+
+    if manifest.package.is_some() {
+        members.insert(root_dir);
+    }
+
+    for member in members {
+        let path = member.join("Cargo.toml");
+
+        match resolve_member(&path, inherited.as_ref()) {
+            Ok((name, _)) if resolved.packages.contains_key(&name) => {
+                let reason = ErrorReason::DuplicatePackage { name };
+                let error = ManifestError::new(Location::default(), reason).with_path(&path);
+
+                resolved.errors.push(error);
+            }
+            Ok((name, version)) => {
+                resolved.packages.insert(name, version);
+            }
+            Err(error) => resolved.errors.push(error.with_path(&path)),
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Resolve the package name and `rust-version` of a single workspace member, where the
+/// `rust-version` is `None` if the member does not specify one.
+fn resolve_member(
+    path: &Path,
+    inherited: Option<&Version>,
+) -> Result<(String, Option<Version>), ManifestError> {
+    let contents = read(path)?;
+    let package = Manifest::parse(&contents)?
+        .package
+        .ok_or_else(|| ManifestError::new(Location::default(), ErrorReason::MissingName))?;
+
+    let name = package
+        .name
+        .ok_or_else(|| ManifestError::new(Location::default(), ErrorReason::MissingName))?;
+
+    let value = match package.rust_version {
+        Some(value) => value,
+        None => return Ok((name, None)),
+    };
+
+    let line = line_of(&contents, value.span().start);
+
+    let version = match rust_version(value.into_inner(), line)? {
+        Some(version) => version,
+        None => inherited.cloned().ok_or_else(|| {
+            ManifestError::new(Location::at_line(line), ErrorReason::MissingWorkspaceKey)
+        })?,
+    };
+
+    Ok((name, Some(version)))
+}
+
+/// Expand the given glob `patterns`, relative to the workspace `root`, into the matching paths.
+///
+/// If `require_literals` is set, a pattern without glob syntax must match an existing path, like
+/// Cargo requires of the entries of `workspace.members`.
+///
+/// An error of a pattern is returned, while an error of a matched path which can't be read is
+/// added to the `errors`, with that path, and the remaining paths are still expanded.
+fn expand_globs(
+    root: &Path,
+    patterns: &[String],
+    require_literals: bool,
+    errors: &mut Vec<ManifestError>,
+) -> Result<Vec<PathBuf>, ManifestError> {
+    let escaped_root = glob::Pattern::escape(&root.to_string_lossy());
+    let mut paths = Vec::new();
+
+    for pattern in patterns {
+        if require_literals && !is_glob(pattern) && !root.join(pattern).exists() {
+            let reason = ErrorReason::MissingMember {
+                member: pattern.clone(),
+            };
+
+            return Err(ManifestError::new(Location::default(), reason));
+        }
+
+        let pattern = Path::new(&escaped_root).join(pattern);
+        let matches = glob::glob(&pattern.to_string_lossy())
+            .map_err(|error| ManifestError::new(Location::default(), ErrorReason::Glob(error)))?;
+
+        for entry in matches {
+            match entry {
+                Ok(path) => paths.push(path),
+                Err(error) => {
+                    let path = error.path().to_path_buf();
+                    let reason = ErrorReason::Io(error.into());
+
+                    errors.push(ManifestError::new(Location::default(), reason).with_path(&path));
+                }
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Whether the given pattern contains glob syntax, rather than being a literal path.
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(|c| matches!(c, '*' | '?' | '['))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::NumericError;
+    use std::fs;
+
+    struct Fixture {
+        dir: tempfile::TempDir,
+    }
+
+    impl Fixture {
+        fn new(root: &str) -> Self {
+            let fixture = Self {
+                dir: tempfile::tempdir().unwrap(),
+            };
+
+            fixture.file("Cargo.toml", root)
+        }
+
+        fn file(self, path: &str, contents: &str) -> Self {
+            let path = self.dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+            self
+        }
+
+        fn package(self, dir: &str, name: &str, rust_version: &str) -> Self {
+            let contents = format!(
+                "[package]\nname = \"{}\"\nversion = \"0.1.0\"\n{}\n",
+                name, rust_version
+            );
+
+            self.file(&format!("{}/Cargo.toml", dir), &contents)
+        }
+
+        fn path(&self, path: &str) -> PathBuf {
+            self.dir.path().join(path)
+        }
+
+        fn resolve(&self) -> Result<WorkspaceRustVersions, ManifestError> {
+            workspace_rust_versions(self.dir.path())
+        }
+
+        // Resolve the workspace, and expect it to resolve each member.
+        fn packages(&self) -> BTreeMap<String, Option<Version>> {
+            let resolved = self.resolve().unwrap();
+
+            assert!(resolved.errors.is_empty(), "{:?}", resolved.errors);
+            resolved.packages
+        }
+
+        // Resolve the workspace, and expect a single member to fail to resolve.
+        fn member_error(&self) -> ManifestError {
+            let mut resolved = self.resolve().unwrap();
+
+            assert_eq!(resolved.errors.len(), 1, "{:?}", resolved.errors);
+            resolved.errors.remove(0)
+        }
+    }
+
+    fn versions(entries: &[(&str, Version)]) -> BTreeMap<String, Option<Version>> {
+        entries
+            .iter()
+            .map(|(name, version)| (name.to_string(), Some(version.clone())))
+            .collect()
+    }
+
+    const WORKSPACE: &str = r#"
+[workspace]
+members = ["crates/*"]
+
+[workspace.package]
+rust-version = "1.60"
+"#;
+
+    #[test]
+    fn explicit_and_inherited() {
+        let fixture = Fixture::new(WORKSPACE)
+            .package("crates/a", "a", "rust-version = \"1.56\"")
+            .package("crates/b", "b", "rust-version.workspace = true")
+            .package("crates/c", "c", "rust-version = { workspace = true }");
+
+        let expected = versions(&[
+            ("a", Version::new_base_version(1, 56)),
+            ("b", Version::new_base_version(1, 60)),
+            ("c", Version::new_base_version(1, 60)),
+        ]);
+
+        assert_eq!(fixture.packages(), expected);
+    }
+
+    #[test]
+    fn root_manifest_path() {
+        let fixture = Fixture::new(WORKSPACE).package("crates/a", "a", "rust-version = \"1.56\"");
+
+        let resolved = workspace_rust_versions(fixture.path("Cargo.toml")).unwrap();
+
+        assert_eq!(
+            resolved.packages,
+            versions(&[("a", Version::new_base_version(1, 56))])
+        );
+    }
+
+    #[test]
+    fn root_package() {
+        let root = r#"
+[package]
+name = "root"
+version = "0.1.0"
+rust-version.workspace = true
+
+[workspace]
+members = [".", "member"]
+
+[workspace.package]
+rust-version = "1.70.1"
+"#;
+        let fixture = Fixture::new(root).package("member", "member", "rust-version = \"1.56\"");
+
+        let expected = versions(&[
+            ("member", Version::new_base_version(1, 56)),
+            ("root", Version::new_full_version(1, 70, 1)),
+        ]);
+
+        assert_eq!(fixture.packages(), expected);
+    }
+
+    #[test]
+    fn excluded_members() {
+        let root = r#"
+[workspace]
+members = ["crates/*", "tools/*"]
+exclude = ["crates/excluded", "tools/*"]
+"#;
+        let fixture = Fixture::new(root)
+            .package("crates/a", "a", "rust-version = \"1.56\"")
+            .package("crates/excluded", "excluded", "")
+            .package("tools/tool", "tool", "")
+            .file("crates/README.md", "not a member");
+
+        let expected = versions(&[("a", Version::new_base_version(1, 56))]);
+
+        assert_eq!(fixture.packages(), expected);
+    }
+
+    #[test]
+    fn not_a_workspace() {
+        let fixture = Fixture::new("[package]\nname = \"a\"\nrust-version = \"1.56\"");
+
+        let err = fixture.resolve().unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::NotAWorkspace));
+        assert_eq!(err.path(), Some(fixture.path("Cargo.toml").as_path()));
+    }
+
+    #[test]
+    fn missing_workspace_key() {
+        let fixture = Fixture::new("[workspace]\nmembers = [\"a\"]").package(
+            "a",
+            "a",
+            "rust-version.workspace = true",
+        );
+
+        let err = fixture.member_error();
+
+        assert!(matches!(err.reason(), ErrorReason::MissingWorkspaceKey));
+        assert_eq!(err.path(), Some(fixture.path("a/Cargo.toml").as_path()));
+        assert_eq!(err.line(), Some(4));
+    }
+
+    #[test]
+    fn missing_member_key() {
+        let fixture = Fixture::new(WORKSPACE)
+            .package("crates/a", "a", "")
+            .package("crates/b", "b", "rust-version = \"1.56\"");
+
+        let mut expected = versions(&[("b", Version::new_base_version(1, 56))]);
+        expected.insert("a".to_string(), None);
+
+        assert_eq!(fixture.packages(), expected);
+    }
+
+    #[test]
+    fn other_members_resolved_on_member_error() {
+        let fixture = Fixture::new(WORKSPACE)
+            .package("crates/a", "a", "rust-version = \"1.56\"")
+            .package("crates/b", "b", "rust-version = \"1.056\"")
+            .package("crates/c", "c", "rust-version.workspace = true");
+
+        let resolved = fixture.resolve().unwrap();
+
+        let expected = versions(&[
+            ("a", Version::new_base_version(1, 56)),
+            ("c", Version::new_base_version(1, 60)),
+        ]);
+        assert_eq!(resolved.packages, expected);
+
+        assert_eq!(resolved.errors.len(), 1);
+        assert!(matches!(
+            resolved.errors[0].reason(),
+            ErrorReason::ParserError(error) if *error.reason() == NumericError::LeadingZero.into()
+        ));
+        assert_eq!(
+            resolved.errors[0].path(),
+            Some(fixture.path("crates/b/Cargo.toml").as_path())
+        );
+    }
+
+    #[test]
+    fn missing_literal_member() {
+        let fixture = Fixture::new("[workspace]\nmembers = [\"crates/a\", \"crates/b\"]").package(
+            "crates/a",
+            "a",
+            "rust-version = \"1.56\"",
+        );
+
+        let err = fixture.resolve().unwrap_err();
+
+        assert!(
+            matches!(err.reason(), ErrorReason::MissingMember { member } if member == "crates/b")
+        );
+        assert_eq!(err.path(), Some(fixture.path("Cargo.toml").as_path()));
+    }
+
+    #[test]
+    fn unmatched_glob_member() {
+        let fixture = Fixture::new("[workspace]\nmembers = [\"crates/*\", \"tools/*\"]").package(
+            "crates/a",
+            "a",
+            "rust-version = \"1.56\"",
+        );
+
+        let expected = versions(&[("a", Version::new_base_version(1, 56))]);
+
+        assert_eq!(fixture.packages(), expected);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_glob_entry() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let fixture = Fixture::new("[workspace]\nmembers = [\"crates/*\", \"vendor/*/*\"]")
+            .package("crates/a", "a", "rust-version = \"1.56\"");
+
+        let locked = fixture.path("vendor/locked");
+        fs::create_dir_all(&locked).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+
+        // Permissions are not enforced for a privileged user, so the entry can't fail to be read.
+        let enforced = fs::read_dir(&locked).is_err();
+        let resolved = fixture.resolve();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+
+        if !enforced {
+            return;
+        }
+
+        let resolved = resolved.unwrap();
+        let expected = versions(&[("a", Version::new_base_version(1, 56))]);
+
+        assert_eq!(resolved.packages, expected);
+        assert_eq!(resolved.errors.len(), 1, "{:?}", resolved.errors);
+        assert!(matches!(resolved.errors[0].reason(), ErrorReason::Io(_)));
+        assert_eq!(resolved.errors[0].path(), Some(locked.as_path()));
+    }
+
+    #[test]
+    fn missing_member_name() {
+        let fixture = Fixture::new(WORKSPACE)
+            .file("crates/a/Cargo.toml", "[package]\nrust-version = \"1.56\"");
+
+        let err = fixture.member_error();
+
+        assert!(matches!(err.reason(), ErrorReason::MissingName));
+        assert_eq!(
+            err.path(),
+            Some(fixture.path("crates/a/Cargo.toml").as_path())
+        );
+    }
+
+    #[test]
+    fn missing_member_manifest() {
+        let fixture = Fixture::new(WORKSPACE).file("crates/a/src/lib.rs", "");
+
+        let err = fixture.member_error();
+
+        assert!(matches!(err.reason(), ErrorReason::Io(_)));
+        assert_eq!(
+            err.path(),
+            Some(fixture.path("crates/a/Cargo.toml").as_path())
+        );
+    }
+
+    #[test]
+    fn invalid_workspace_version() {
+        let root = "[workspace]\nmembers = []\n\n[workspace.package]\nrust-version = \"1.056\"";
+        let fixture = Fixture::new(root);
+
+        let err = fixture.resolve().unwrap_err();
+
+        assert!(matches!(
+            err.reason(),
+            ErrorReason::ParserError(error) if *error.reason() == NumericError::LeadingZero.into()
+        ));
+        assert_eq!(err.path(), Some(fixture.path("Cargo.toml").as_path()));
+        assert_eq!(err.line(), Some(5));
+    }
+
+    #[test]
+    fn duplicate_package() {
+        let fixture = Fixture::new(WORKSPACE)
+            .package("crates/a", "same", "rust-version = \"1.56\"")
+            .package("crates/b", "same", "rust-version = \"1.56\"");
+
+        let err = fixture.member_error();
+
+        assert!(matches!(err.reason(), ErrorReason::DuplicatePackage { name } if name == "same"));
+        assert_eq!(
+            err.path(),
+            Some(fixture.path("crates/b/Cargo.toml").as_path())
+        );
+    }
+
+    #[test]
+    fn invalid_glob() {
+        let fixture = Fixture::new("[workspace]\nmembers = [\"crates/[\"]");
+
+        let err = fixture.resolve().unwrap_err();
+
+        assert!(matches!(err.reason(), ErrorReason::Glob(_)));
+        assert_eq!(err.path(), Some(fixture.path("Cargo.toml").as_path()));
+    }
+}
@@ -0,0 +1,214 @@
+//! Combinators for [`nom`], to parse version numbers as part of a larger grammar.
+//!
+//! Requires the `nom` feature to be enabled.
+//!
+//! The combinators accept any input which implements [`Input`] and [`AsBytes`], such as `&str`
+//! and `&[u8]`, and share the component parsing of the [`modular`] parser, so leading zeros and
+//! components which overflow a `u64` are rejected.
+//!
+//! Like the `parse_*_prefix` methods of the parsers, the combinators do not expect the
+//! end-of-input after the version. [`version`] is greedy: it parses the patch component if the
+//! minor component is followed by a separator and a digit.
+//!
+//! # Errors
+//!
+//! A missing separator is reported as [`ErrorKind::Char`], and a missing or invalid component as
+//! [`ErrorKind::Digit`]. When a component has a leading zero or overflows, the [`NumberError`]
+//! is passed to [`FromExternalError::from_external_error`]. All errors are recoverable
+//! [`Err::Error`]s, which may be turned into failures with `nom::combinator::cut`.
+//!
+//! # Example
+//!
+//! ```
+//! use nom::bytes::complete::tag;
+//! use nom::sequence::preceded;
+//! use nom::Parser;
+//! use version_number::Version;
+//!
+//! let mut parser = preceded(tag("foo-"), version_number::nom::version::<_, nom::error::Error<_>>);
+//! let (rest, version) = parser.parse("foo-1.2.3.tar.gz").unwrap();
+//!
+//! assert_eq!(version, Version::new_full_version(1, 2, 3));
+//! assert_eq!(rest, ".tar.gz");
+//! ```
+//!
+//! [`nom`]: https://docs.rs/nom
+//! [`modular`]: crate::parsers::modular
+//! [`NumberError`]: crate::parsers::modular::NumberError
+
+use crate::parsers::modular::component::{parse_component_prefix, starts_with_patch};
+use crate::parsers::modular::{ModularParserError, NumberError};
+use crate::{BaseVersion, FullVersion, Version};
+use ::nom::error::{ErrorKind, FromExternalError, ParseError};
+use ::nom::{AsBytes, Err, IResult, Input};
+
+/// Parse a single version component, i.e. a number without leading zeros.
+pub fn component<I, E>(input: I) -> IResult<I, u64, E>
+where
+    I: Input + AsBytes,
+    E: ParseError<I> + FromExternalError<I, NumberError>,
+{
+    match parse_component_prefix(input.as_bytes()) {
+        Ok((value, consumed)) => {
+            let (rest, _) = input.take_split(consumed);
+            Ok((rest, value))
+        }
+        Err(ModularParserError::NumberError { error, .. }) => Err(Err::Error(
+            E::from_external_error(input, ErrorKind::Digit, error),
+        )),
+        Err(_) => Err(Err::Error(E::from_error_kind(input, ErrorKind::Digit))),
+    }
+}
+
+/// Parse a two component `major.minor` [`BaseVersion`].
+///
+/// Parsing stops after the minor component, so for an input like `1.2.3`, the remaining input
+/// is `.3`.
+pub fn base_version<I, E>(input: I) -> IResult<I, BaseVersion, E>
+where
+    I: Input + AsBytes,
+    E: ParseError<I> + FromExternalError<I, NumberError>,
+{
+    let (input, major) = component(input)?;
+    let (input, ()) = separator(input)?;
+    let (input, minor) = component(input)?;
+
+    Ok((input, BaseVersion::new(major, minor)))
+}
+
+/// Parse a three component `major.minor.patch` [`FullVersion`].
+pub fn full_version<I, E>(input: I) -> IResult<I, FullVersion, E>
+where
+    I: Input + AsBytes,
+    E: ParseError<I> + FromExternalError<I, NumberError>,
+{
+    let (input, base) = base_version(input)?;
+    let (input, ()) = separator(input)?;
+    let (input, patch) = component(input)?;
+
+    Ok((input, FullVersion::new(base.major, base.minor, patch)))
+}
+
+/// Parse a two component [`Version::Base`], or a three component [`Version::Full`].
+///
+/// The patch component is parsed if the minor component is followed by a separator and a digit,
+/// so for an input like `1.2.x`, a base version is parsed, and the remaining input is `.x`.
+pub fn version<I, E>(input: I) -> IResult<I, Version, E>
+where
+    I: Input + AsBytes,
+    E: ParseError<I> + FromExternalError<I, NumberError>,
+{
+    let (input, base) = base_version(input)?;
+
+    if !starts_with_patch(input.as_bytes()) {
+        return Ok((input, Version::Base(base)));
+    }
+
+    let (input, ()) = separator(input)?;
+    let (input, patch) = component(input)?;
+
+    Ok((
+        input,
+        Version::Full(FullVersion::new(base.major, base.minor, patch)),
+    ))
+}
+
+// Parse the `.` separator between two components.
+fn separator<I, E>(input: I) -> IResult<I, (), E>
+where
+    I: Input + AsBytes,
+    E: ParseError<I>,
+{
+    match input.as_bytes().first() {
+        Some(b'.') => Ok((input.take_from(1), ())),
+        _ => Err(Err::Error(E::from_error_kind(input, ErrorKind::Char))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::nom::error::Error;
+
+    type Result<'a, O> = IResult<&'a str, O, Error<&'a str>>;
+
+    #[yare::parameterized(
+        base = { "1.2", Version::new_base_version(1, 2), "" },
+        full = { "1.2.3", Version::new_full_version(1, 2, 3), "" },
+        archive = { "1.2.3.tar.gz", Version::new_full_version(1, 2, 3), ".tar.gz" },
+        rustc = { "1.70.0 (90c541806 2023-05-31)", Version::new_full_version(1, 70, 0), " (90c541806 2023-05-31)" },
+        dot_not_followed_by_digit = { "1.2.x", Version::new_base_version(1, 2), ".x" },
+        label = { "1.2-beta", Version::new_base_version(1, 2), "-beta" },
+    )]
+    fn accepted(input: &str, expected: Version, rest: &str) {
+        let result: Result<'_, Version> = version(input);
+
+        assert_eq!(result, Ok((rest, expected)));
+    }
+
+    #[yare::parameterized(
+        empty = { "", "", ErrorKind::Digit },
+        no_separator = { "1", "", ErrorKind::Char },
+        not_a_separator = { "1-2", "-2", ErrorKind::Char },
+        no_minor = { "1.", "", ErrorKind::Digit },
+        leading_zero = { "1.02", "02", ErrorKind::Digit },
+        overflow = { "18446744073709551616.0", "18446744073709551616.0", ErrorKind::Digit },
+        patch_leading_zero = { "1.2.03", "03", ErrorKind::Digit },
+    )]
+    fn rejected(input: &str, at: &str, kind: ErrorKind) {
+        let result: Result<'_, Version> = version(input);
+
+        assert_eq!(result, Err(Err::Error(Error::new(at, kind))));
+    }
+
+    #[test]
+    fn base_version_stops_after_minor() {
+        let result: Result<'_, BaseVersion> = base_version("1.2.3");
+
+        assert_eq!(result, Ok((".3", BaseVersion::new(1, 2))));
+    }
+
+    #[test]
+    fn full_version_requires_patch() {
+        let result: Result<'_, FullVersion> = full_version("1.2 ");
+
+        assert_eq!(result, Err(Err::Error(Error::new(" ", ErrorKind::Char))));
+    }
+
+    #[test]
+    fn bytes() {
+        let result: IResult<&[u8], FullVersion, Error<&[u8]>> = full_version(&b"1.2.3\n"[..]);
+
+        assert_eq!(result, Ok((&b"\n"[..], FullVersion::new(1, 2, 3))));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct NumberErrorKind(Option<NumberError>);
+
+    impl<I> ParseError<I> for NumberErrorKind {
+        fn from_error_kind(_: I, _: ErrorKind) -> Self {
+            Self(None)
+        }
+
+        fn append(_: I, _: ErrorKind, other: Self) -> Self {
+            other
+        }
+    }
+
+    impl<I> FromExternalError<I, NumberError> for NumberErrorKind {
+        fn from_external_error(_: I, _: ErrorKind, e: NumberError) -> Self {
+            Self(Some(e))
+        }
+    }
+
+    #[yare::parameterized(
+        leading_zero = { "01", Some(NumberError::LeadingZero) },
+        overflow = { "18446744073709551616", Some(NumberError::Overflow) },
+        not_a_number = { "x", None },
+    )]
+    fn number_error(input: &str, expected: Option<NumberError>) {
+        let result: IResult<&str, u64, NumberErrorKind> = component(input);
+
+        assert_eq!(result, Err(Err::Error(NumberErrorKind(expected))));
+    }
+}
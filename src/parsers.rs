@@ -12,6 +12,8 @@
 //! It can be used to parse two- or three-component `major.minor(.patch)` versions,
 //! where the patch version may or may not be present.
 //!
+//! Requires the `std` feature.
+//!
 //! ## Modular
 //!
 //! The [`modular::Parser`] has an API which is based on the _type state_ pattern.
@@ -19,7 +21,38 @@
 //! The modular parser can parse a version incrementally.
 //!
 //! It may be used to parse either a two component `major.minor` version, or a three component
-//! `major.minor.patch` version, or both.
+//! `major.minor.patch` version, or both. It is also available without the `std` feature.
+//!
+//! ## Labels
+//!
+//! Both the original and modular parsers have an opt-in mode to parse a [`LabeledVersion`],
+//! which is a version which may be followed by a pre-release label and/or build metadata, such
+//! as `1.70.0-beta.3+build.5`: [`original::Parser::parse_labeled`] and
+//! [`modular::Parser::parse_labeled`]. By default, these labels are rejected.
+//!
+//! ## Lenient
+//!
+//! The [`lenient::LenientParser`] accepts inputs which the parsers above reject, but which are
+//! commonly seen in the wild, such as `v1.2`, ` 1.2.3\n` and `22.04`. It normalizes the input
+//! before parsing it with the original parser, and reports which normalizations were applied.
+//!
+//! ## Requirement
+//!
+//! The [`requirement::Parser`] parses a version requirement, such as `>=1.56, <1.70`, to a
+//! [`VersionReq`]. Unlike the parsers above, it does not parse a [`Version`], and therefore
+//! does not implement the [`VersionParser`] trait.
+//!
+//! # Prefixes
+//!
+//! The parsers reject input which remains after the version. To parse a version which is part
+//! of a larger input, such as `foo-1.2.3.tar.gz`, the `parse_*_prefix` methods of the parser
+//! traits, and the `parse_prefix` methods of the parsers, return the version as a [`Prefix`],
+//! together with the remainder of the input.
+//!
+//! # Errors
+//!
+//! The errors of the parsers carry the [`Span`] of the input at which parsing failed. The
+//! [`render`] module renders such a span as a snippet of the input, with its line and column.
 //!
 //! # Example
 //!
@@ -44,14 +77,29 @@
 //! assert_eq!(original_parsed, modular_parsed );
 //! ```
 //!
+//! [`VersionReq`]: crate::VersionReq
+//! [`LabeledVersion`]: crate::LabeledVersion
+//! [`original::Parser`]: crate::parsers::original::Parser
+//! [`original::Parser::parse`]: crate::parsers::original::Parser::parse
+//! [`original::Parser::parse_labeled`]: crate::parsers::original::Parser::parse_labeled
+//! [`render`]: crate::parsers::render
+//! [`Span`]: crate::parsers::Span
+//! [`Prefix`]: crate::parsers::Prefix
 
 use crate::{BaseVersion, FullVersion, Version};
 
-pub use error::{NumericError, ParserError};
+pub use error::{LabelError, NumericError, ParserError, Span, Token, TokenSet};
 
 pub mod error;
+#[cfg(feature = "std")]
+pub(crate) mod labels;
+#[cfg(feature = "std")]
+pub mod lenient;
 pub mod modular;
+#[cfg(feature = "std")]
 pub mod original;
+pub mod render;
+pub mod requirement;
 
 /// Parse a UTF-8 slice of bytes to a [`Version`].
 pub trait VersionParser {
@@ -62,6 +110,15 @@ pub trait VersionParser {
     /// a base- or full version, you may instead use [`BaseVersionParser::parse_base`]
     /// or [`FullVersionParser::parse_full`].
     fn parse_version<B: AsRef<[u8]>>(&self, input: B) -> Result<Version, ParserError>;
+
+    /// Parse a [`Version`] from the start of the `input`, and return it together with the
+    /// remainder of the input, which was not parsed.
+    ///
+    /// Parsing is greedy: a third component is parsed if the minor component is followed by a
+    /// separator and a digit, and parsing stops at the first byte which can't continue the
+    /// version. Unlike [`VersionParser::parse_version`], remaining input is not an error.
+    fn parse_version_prefix<'b>(&self, input: &'b [u8])
+        -> Result<Prefix<'b, Version>, ParserError>;
 }
 
 /// Parse a UTF-8 slice of bytes to a [`BaseVersion`].
@@ -71,6 +128,16 @@ pub trait BaseVersionParser {
     /// If you don't know, or care, whether the version consists of two or three components,
     /// you may instead use [`VersionParser::parse_version`].
     fn parse_base<B: AsRef<[u8]>>(&self, input: B) -> Result<BaseVersion, ParserError>;
+
+    /// Parse a [`BaseVersion`] from the start of the `input`, and return it together with the
+    /// remainder of the input, which was not parsed.
+    ///
+    /// Parsing stops after the minor component, so for an input like `1.2.3`, the remainder
+    /// is `.3`.
+    fn parse_base_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, BaseVersion>, ParserError>;
 }
 
 /// Parse a UTF-8 slice of bytes to a [`FullVersion`].
@@ -80,9 +147,67 @@ pub trait FullVersionParser {
     /// If you don't know, or care, whether the version consists of two or three components,
     /// you may instead use [`VersionParser::parse_version`].
     fn parse_full<B: AsRef<[u8]>>(&self, input: B) -> Result<FullVersion, ParserError>;
+
+    /// Parse a [`FullVersion`] from the start of the `input`, and return it together with the
+    /// remainder of the input, which was not parsed.
+    ///
+    /// Parsing stops after the patch component, at the first byte which is not a digit.
+    fn parse_full_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, FullVersion>, ParserError>;
 }
 
-#[cfg(test)]
+/// A value parsed from the start of an input, together with the remainder of the input which
+/// was not parsed.
+///
+/// # Example
+///
+/// ```
+/// use version_number::parsers::modular::ModularParser;
+/// use version_number::parsers::VersionParser;
+/// use version_number::Version;
+///
+/// let prefix = ModularParser.parse_version_prefix(b"1.2.3.tar.gz").unwrap();
+///
+/// assert_eq!(prefix.value, Version::new_full_version(1, 2, 3));
+/// assert_eq!(prefix.remainder, b".tar.gz");
+/// assert_eq!(prefix.consumed, 5);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Prefix<'input, T> {
+    /// The parsed value.
+    pub value: T,
+    /// The remainder of the input, which starts at the first byte which was not parsed.
+    pub remainder: &'input [u8],
+    /// The number of bytes of the input which were parsed.
+    pub consumed: usize,
+}
+
+impl<'input, T> Prefix<'input, T> {
+    /// Split the `input` after the `consumed` number of bytes, which were parsed to the `value`.
+    pub(crate) fn new(value: T, input: &'input [u8], consumed: usize) -> Self {
+        Self {
+            value,
+            remainder: &input[consumed..],
+            consumed,
+        }
+    }
+
+    /// Map the parsed value, while keeping the remainder of the input.
+    pub fn map<U, F>(self, f: F) -> Prefix<'input, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Prefix {
+            value: f(self.value),
+            remainder: self.remainder,
+            consumed: self.consumed,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -115,4 +240,31 @@ mod tests {
         let v = example_generic(input, modular::ModularParser);
         assert_eq!(v, expected);
     }
+
+    fn example_prefix<T: VersionParser + BaseVersionParser + FullVersionParser>(parser: T) {
+        let input = b"foo-1.2.3.tar.gz";
+
+        let prefix = parser.parse_version_prefix(&input[4..]).unwrap();
+        assert_eq!(prefix.value, Version::new_full_version(1, 2, 3));
+        assert_eq!(prefix.remainder, b".tar.gz");
+        assert_eq!(prefix.consumed, 5);
+
+        let prefix = parser.parse_base_prefix(&input[4..]).unwrap();
+        assert_eq!(prefix.value, BaseVersion::new(1, 2));
+        assert_eq!(prefix.remainder, b".3.tar.gz");
+
+        let prefix = parser.parse_full_prefix(&input[4..]).unwrap();
+        assert_eq!(prefix.value, FullVersion::new(1, 2, 3));
+        assert_eq!(prefix.remainder, b".tar.gz");
+
+        assert!(parser.parse_full_prefix(b"1.2 ").is_err());
+        assert!(parser.parse_version_prefix(input).is_err());
+    }
+
+    #[test]
+    fn parse_prefix_generic() {
+        example_prefix(original::OriginalParser);
+        example_prefix(modular::ModularParser);
+        example_prefix(lenient::LenientParser);
+    }
 }
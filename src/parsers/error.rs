@@ -7,85 +7,534 @@
 //! [`ParseBase`]: crate::BaseVersionParser
 //! [`ParseFull`]: crate::FullVersionParser
 
-type Index = usize;
+#[cfg(feature = "std")]
+use crate::parsers::render::Snippet;
+use crate::parsers::render::{self, Byte, Char};
+use core::fmt;
 
+/// The common error type of the version parsers.
 ///
-#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
-pub enum ParserError {
+/// Besides the [`reason`] why the input could not be parsed, it carries the [`span`] of the
+/// input at which parsing failed. When a different token was expected, the [`got`] and
+/// [`expected`] methods describe the offending token, and the set of tokens which would have
+/// been accepted instead.
+///
+/// [`reason`]: ParserError::reason
+/// [`span`]: ParserError::span
+/// [`got`]: ParserError::got
+/// [`expected`]: ParserError::expected
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParserError {
+    span: Span,
+    reason: ErrorReason,
+}
+
+impl ParserError {
+    /// Create a new error for the given `span` of the input.
+    pub fn new(span: Span, reason: impl Into<ErrorReason>) -> Self {
+        Self {
+            span,
+            reason: reason.into(),
+        }
+    }
+
+    /// The span of the input at which parsing failed.
+    ///
+    /// For an unexpected token, this is the span of that token, which is empty when the
+    /// end-of-input was reached. For a number or label which could not be parsed, this is the span
+    /// of that number or label.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The reason why the input could not be parsed.
+    pub fn reason(&self) -> &ErrorReason {
+        &self.reason
+    }
+
+    /// The offending token, if a different token was expected.
+    ///
+    /// Returns `None` if the end-of-input was reached instead, or if the error is not caused by
+    /// an unexpected token.
+    pub fn got(&self) -> Option<char> {
+        match &self.reason {
+            ErrorReason::Expected(error) => error.got,
+            _ => None,
+        }
+    }
+
+    /// The set of tokens which would have been accepted at the [`span`], if a different token was
+    /// expected.
+    ///
+    /// Returns an empty set if the error is not caused by an unexpected token.
+    ///
+    /// [`span`]: ParserError::span
+    pub fn expected(&self) -> TokenSet {
+        match &self.reason {
+            ErrorReason::Expected(error) => error.expected,
+            _ => TokenSet::EMPTY,
+        }
+    }
+
+    /// Render a snippet of the `input`, which underlines the [`span`] at which parsing failed.
+    ///
+    /// The `input` should be the input which was parsed, or the snippet will point at the wrong
+    /// location.
+    ///
+    /// [`span`]: ParserError::span
+    #[cfg(feature = "std")]
+    pub fn render<'a, B: AsRef<[u8]> + ?Sized>(&self, input: &'a B) -> Snippet<'a> {
+        Snippet::new(input, self.span).with_expected(self.expected())
+    }
+
+    /// Replace the span, e.g. to refer to an input from which the parsed input was derived.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Decode the offending token from the `input`, since a parser which reads the input byte by
+    /// byte only knows the first byte of a multi-byte character. The span is widened to the
+    /// complete character.
+    pub(crate) fn decode_got(mut self, input: &[u8]) -> Self {
+        if let ErrorReason::Expected(ExpectedError {
+            got: got @ Some(_), ..
+        }) = &mut self.reason
+        {
+            if let Some((c, len)) = render::char_at(input, self.span.start) {
+                *got = Some(c);
+                self.span.end = self.span.start + len;
+            }
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {})", self.reason, self.span)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
+
+/// Reasons for why a given input cannot be parsed to a version.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ErrorReason {
     /// An error variant for fault when a some type of input, or none at all,
     /// was expected next.
-    #[error(transparent)]
-    Expected(#[from] ExpectedError),
+    Expected(ExpectedError),
 
     /// An error variant for faults when parsing and constructing a number.
-    #[error(transparent)]
-    Numeric(#[from] NumericError),
+    Numeric(NumericError),
+
+    /// An error variant for faults when parsing the labels of a [`LabeledVersion`].
+    ///
+    /// [`LabeledVersion`]: crate::LabeledVersion
+    Label(LabelError),
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Expected(e) => fmt::Display::fmt(e, f),
+            Self::Numeric(e) => fmt::Display::fmt(e, f),
+            Self::Label(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErrorReason {}
+
+impl From<ExpectedError> for ErrorReason {
+    fn from(error: ExpectedError) -> Self {
+        Self::Expected(error)
+    }
+}
+
+impl From<NumericError> for ErrorReason {
+    fn from(error: NumericError) -> Self {
+        Self::Numeric(error)
+    }
+}
+
+impl From<LabelError> for ErrorReason {
+    fn from(error: LabelError) -> Self {
+        Self::Label(error)
+    }
 }
 
 /// An error type for faults relating to parsing and expecting a certain type of
 /// token.
-#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
-pub enum ExpectedError {
-    /// When this error variant is returned, the `.` token, i.e. the separator, was expected, but
-    /// a different token was present. The `got` field shows the token read.
-    #[error("Expected dot token '.', but got '{}'{}.", 
-        .got.map(String::from).unwrap_or_else(|| "EOI".to_string()),
-        .at.map(|i| format!(" at {}", i)).unwrap_or_default())
-    ]
-    Separator {
-        /// Place where the token was expected.
-        ///
-        /// May be `None` if the respective parser does not store the index.
-        at: Option<Index>,
-        /// Token found instead, or `None` if we unexpectedly got the end-of-input.
-        got: Option<char>,
-    },
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpectedError {
+    /// The set of tokens which would have been accepted.
+    pub expected: TokenSet,
+    /// Token found instead, or `None` if we unexpectedly got the end-of-input.
+    pub got: Option<char>,
+}
 
-    /// When this error variant is returned, the parser expected that no more
-    /// tokens should be present, but instead 1 or more additional tokens
-    /// were not parsed yet.
-    ///
-    /// The `got` field contains the next token received, where
-    /// it expected none to be remaining.
-    #[error("Expected end of input, but got '{got}'{}.", .at.map(|i| format!(" at {}", i)).unwrap_or_default())]
-    EndOfInput {
-        /// Place where the end-of-input was expected.
-        ///
-        /// May be `None` if the respective parser does not store the index.
-        at: Option<Index>,
-        /// Character found at the expected end-of-input.
-        got: char,
-    },
+impl fmt::Display for ExpectedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected {}, but got '{}'",
+            self.expected,
+            Char(self.got)
+        )
+    }
+}
 
-    /// When this error variant is returned, a numeric token was expected, but
-    /// a different token was present.
-    ///
-    /// The `got` field shows the token read.
-    #[error("Expected numeric token (0-9), but got '{}'{}.",
-        .got.map(String::from).unwrap_or_else(|| "EOI".to_string()),
-        .at.map(|i| format!(" at {}", i)).unwrap_or_default())
-    ]
-    Numeric {
-        /// Place where the token was expected.
-        ///
-        /// May be `None` if the respective parser does not store the index.
-        at: Option<Index>,
-        /// Token found instead, or `None` if we unexpectedly got the end-of-input.
-        got: Option<char>,
-    },
+#[cfg(feature = "std")]
+impl std::error::Error for ExpectedError {}
+
+impl ExpectedError {
+    /// Convert a single byte token to the character found, where a byte which is not ASCII, and
+    /// therefore part of a multi-byte character, becomes the `U+FFFD` replacement character.
+    pub(crate) fn got_byte(got: Option<u8>) -> Option<char> {
+        got.map(|b| {
+            if b.is_ascii() {
+                char::from(b)
+            } else {
+                char::REPLACEMENT_CHARACTER
+            }
+        })
+    }
+}
+
+/// A range of byte offsets into the parsed input, from `start` up to, but excluding, `end`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Span {
+    /// The byte offset of the start of the span.
+    pub start: usize,
+    /// The byte offset of the end of the span, which is not included in the span itself.
+    pub end: usize,
+}
+
+impl Span {
+    /// Create a new span from `start` up to, but excluding, `end`.
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Create an empty span at the given byte offset, e.g. to point at the end-of-input.
+    pub const fn empty(at: usize) -> Self {
+        Self::new(at, at)
+    }
+
+    /// Create a span for the token at the given byte offset, or an empty span if the token
+    /// is absent, i.e. if the end-of-input was reached.
+    pub(crate) fn token<T>(at: usize, token: Option<T>) -> Self {
+        match token {
+            Some(_) => Self::new(at, at + 1),
+            None => Self::empty(at),
+        }
+    }
+
+    /// The length of the span in bytes.
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Whether the span is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A kind of token which may be expected by a parser.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Token {
+    /// A digit, `0` up to and including `9`.
+    Digit,
+    /// The `.` separator.
+    Dot,
+    /// The `-` which starts a pre-release label.
+    Hyphen,
+    /// The `+` which starts the build metadata.
+    Plus,
+    /// The end of the input.
+    EndOfInput,
+}
+
+impl Token {
+    const ALL: [Token; 5] = [
+        Token::Digit,
+        Token::Dot,
+        Token::Hyphen,
+        Token::Plus,
+        Token::EndOfInput,
+    ];
+
+    const fn bit(self) -> u8 {
+        match self {
+            Token::Digit => 1,
+            Token::Dot => 1 << 1,
+            Token::Hyphen => 1 << 2,
+            Token::Plus => 1 << 3,
+            Token::EndOfInput => 1 << 4,
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Token::Digit => "0-9",
+            Token::Dot => "'.'",
+            Token::Hyphen => "'-'",
+            Token::Plus => "'+'",
+            Token::EndOfInput => "EOI",
+        })
+    }
+}
+
+/// A set of [`Token`] kinds, which were expected by a parser.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct TokenSet(u8);
+
+impl TokenSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self(0);
+
+    /// Returns the set of tokens of `self`, with the given `token` added.
+    pub const fn with(self, token: Token) -> Self {
+        Self(self.0 | token.bit())
+    }
+
+    /// Whether the set contains the given `token`.
+    pub fn contains(&self, token: Token) -> bool {
+        self.0 & token.bit() != 0
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// An iterator over the tokens in the set.
+    pub fn iter(&self) -> impl Iterator<Item = Token> + '_ {
+        Token::ALL
+            .iter()
+            .copied()
+            .filter(move |&token| self.contains(token))
+    }
+}
+
+impl From<Token> for TokenSet {
+    fn from(token: Token) -> Self {
+        Self::EMPTY.with(token)
+    }
+}
+
+impl fmt::Debug for TokenSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Display for TokenSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.iter().count();
+
+        if len == 0 {
+            return f.write_str("nothing");
+        }
+
+        for (i, token) in self.iter().enumerate() {
+            if i + 1 == len && i > 0 {
+                f.write_str(" or ")?;
+            } else if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(&token, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets of tokens which are expected at the various positions of the version parsers.
+pub(crate) mod expected {
+    use super::{Token, TokenSet};
+
+    /// A number component was expected.
+    pub const NUMBER: TokenSet = TokenSet::EMPTY.with(Token::Digit);
+
+    /// A separator was expected, after the major or minor component.
+    pub const SEPARATOR: TokenSet = NUMBER.with(Token::Dot);
+
+    /// The end-of-input was expected, after the last component.
+    pub const END: TokenSet = NUMBER.with(Token::EndOfInput);
+
+    /// The end-of-input, or a separator followed by the patch component, was expected, after the
+    /// minor component.
+    pub const SEPARATOR_OR_END: TokenSet = SEPARATOR.with(Token::EndOfInput);
+
+    /// The end-of-input, or a label, was expected, after the last component.
+    // Labels are only parsed with the `std` feature.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub const LABEL_OR_END: TokenSet = END.with(Token::Hyphen).with(Token::Plus);
+
+    /// The end-of-input, a label, or a separator followed by the patch component, was expected,
+    /// after the minor component.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub const SEPARATOR_LABEL_OR_END: TokenSet = LABEL_OR_END.with(Token::Dot);
 }
 
 /// An error type for faults relating to parsing and constructing numbers.
-#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum NumericError {
     /// When this error variant is returned, the parser detected that the number started with a leading
     /// zero, which is not allowed for number components.
-    #[error("Number may not start with a leading zero, unless the complete component is '0'")]
     LeadingZero,
 
     /// This error variant is returned if the number would overflow.
     ///
     /// Each number component consists of a 64 bits unsigned integer.
-    #[error("Overflow: Found number component which would be larger than the maximum supported number (max={})", u64::MAX)]
     Overflow,
 }
+
+impl fmt::Display for NumericError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LeadingZero => f.write_str(
+                "Number may not start with a leading zero, unless the complete component is '0'",
+            ),
+            Self::Overflow => write!(
+                f,
+                "Overflow: Found number component which would be larger than the maximum supported number (max={})",
+                u64::MAX
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumericError {}
+
+/// An error type for faults relating to parsing the pre-release label or build metadata of a
+/// [`LabeledVersion`].
+///
+/// [`LabeledVersion`]: crate::LabeledVersion
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LabelError {
+    /// When this error variant is returned, a label, or one of its dot separated identifiers,
+    /// was empty.
+    EmptyIdentifier,
+
+    /// When this error variant is returned, a token was found which is not allowed in a label.
+    ///
+    /// The `got` field shows the token read.
+    InvalidCharacter {
+        /// Token read.
+        got: u8,
+    },
+
+    /// When this error variant is returned, a numeric pre-release identifier started with a
+    /// leading zero.
+    LeadingZero,
+}
+
+impl fmt::Display for LabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyIdentifier => f.write_str("Label identifiers may not be empty"),
+            Self::InvalidCharacter { got } => write!(
+                f,
+                "Expected an ASCII alphanumeric, '-' or '.' token in label, but got '{}'",
+                Byte(Some(*got))
+            ),
+            Self::LeadingZero => {
+                f.write_str("Numeric pre-release identifiers may not start with a leading zero")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LabelError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        empty = { TokenSet::EMPTY, "nothing" },
+        single = { TokenSet::from(Token::Dot), "'.'" },
+        two = { expected::END, "0-9 or EOI" },
+        many = { expected::LABEL_OR_END, "0-9, '-', '+' or EOI" },
+    )]
+    fn display_token_set(set: TokenSet, expected: &str) {
+        assert_eq!(set.to_string(), expected);
+    }
+
+    #[test]
+    fn expected_error() {
+        let err = ParserError::new(
+            Span::new(3, 4),
+            ExpectedError {
+                expected: expected::SEPARATOR,
+                got: Some('x'),
+            },
+        );
+
+        assert_eq!(err.span(), Span::new(3, 4));
+        assert_eq!(err.got(), Some('x'));
+        assert_eq!(err.expected(), expected::SEPARATOR);
+        assert_eq!(
+            err.to_string(),
+            "Expected 0-9 or '.', but got 'x' (at 3..4)"
+        );
+    }
+
+    #[yare::parameterized(
+        newline = { Some('\n'), "Expected 0-9 or '.', but got '\\x0A'" },
+        bell = { Some('\u{7}'), "Expected 0-9 or '.', but got '\\x07'" },
+        delete = { Some('\u{7f}'), "Expected 0-9 or '.', but got '\\x7F'" },
+        space = { Some(' '), "Expected 0-9 or '.', but got ' '" },
+        non_ascii = { Some('é'), "Expected 0-9 or '.', but got 'é'" },
+        non_printable = { Some('\u{200b}'), "Expected 0-9 or '.', but got '\\u{200b}'" },
+        end_of_input = { None, "Expected 0-9 or '.', but got 'EOI'" },
+    )]
+    fn expected_error_display(got: Option<char>, expected: &str) {
+        let err = ExpectedError {
+            expected: expected::SEPARATOR,
+            got,
+        };
+
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn version_parse_escapes_newline() {
+        let err = crate::Version::parse("1.2\n").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Expected 0-9, '.' or EOI, but got '\\x0A' (at 3..4)"
+        );
+    }
+
+    #[test]
+    fn numeric_error() {
+        let err = ParserError::new(Span::new(2, 4), NumericError::LeadingZero);
+
+        assert_eq!(
+            err.reason(),
+            &ErrorReason::Numeric(NumericError::LeadingZero)
+        );
+        assert_eq!(err.got(), None);
+        assert!(err.expected().is_empty());
+    }
+}
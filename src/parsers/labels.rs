@@ -0,0 +1,120 @@
+//! Parsing of the pre-release label and build metadata of a [`LabeledVersion`], which is
+//! shared by the parsers which support the opt-in labeled mode.
+//!
+//! [`LabeledVersion`]: crate::LabeledVersion
+
+use crate::parsers::{LabelError, Span};
+use crate::{BuildMetadata, Prerelease};
+use std::iter::Peekable;
+
+/// A label error, with the span of the input at which it occurred.
+pub(crate) type LocatedError = (Span, LabelError);
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Kind {
+    Prerelease,
+    BuildMetadata,
+}
+
+/// Parses an optional `-` prefixed pre-release label, followed by optional `+` prefixed build
+/// metadata. If neither label is present, the iterator is left untouched, so the caller can
+/// report the unexpected token.
+///
+/// The `len` is the length of the complete input, which is used to compute the span of an error
+/// from the remaining length of the iterator.
+pub(crate) fn parse_labels<'b>(
+    input: &mut Peekable<impl ExactSizeIterator<Item = &'b u8>>,
+    len: usize,
+) -> Result<(Prerelease, BuildMetadata), LocatedError> {
+    let pre = match input.next_if_eq(&&b'-') {
+        Some(_) => Prerelease::new_unchecked(parse_identifiers(input, len, Kind::Prerelease)?),
+        None => Prerelease::EMPTY,
+    };
+
+    let build = match input.next_if_eq(&&b'+') {
+        Some(_) => {
+            BuildMetadata::new_unchecked(parse_identifiers(input, len, Kind::BuildMetadata)?)
+        }
+        None => BuildMetadata::EMPTY,
+    };
+
+    Ok((pre, build))
+}
+
+/// Parses a complete label of the given kind, without its `-` or `+` prefix.
+pub(crate) fn parse_label(input: &str, kind: Kind) -> Result<String, LabelError> {
+    let mut iter = input.as_bytes().iter().peekable();
+    let label = parse_identifiers(&mut iter, input.len(), kind).map_err(|(_, error)| error)?;
+
+    match iter.next() {
+        Some(&token) => Err(LabelError::InvalidCharacter { got: token }),
+        None => Ok(label),
+    }
+}
+
+fn parse_identifiers<'b>(
+    input: &mut Peekable<impl ExactSizeIterator<Item = &'b u8>>,
+    len: usize,
+    kind: Kind,
+) -> Result<String, LocatedError> {
+    let mut label = String::new();
+
+    loop {
+        let start = label.len();
+        let offset = len - input.len();
+
+        while let Some(&token) = input.next_if(|&&token| is_identifier_token(token)) {
+            label.push(char::from(token));
+        }
+
+        let identifier = &label[start..];
+
+        if identifier.is_empty() {
+            return Err(match input.peek() {
+                Some(&&token) if !is_terminator(token, kind) => (
+                    Span::new(offset, offset + 1),
+                    LabelError::InvalidCharacter { got: token },
+                ),
+                _ => (Span::empty(offset), LabelError::EmptyIdentifier),
+            });
+        }
+
+        if matches!(kind, Kind::Prerelease) && has_leading_zero(identifier) {
+            return Err((
+                Span::new(offset, offset + identifier.len()),
+                LabelError::LeadingZero,
+            ));
+        }
+
+        if input.next_if_eq(&&b'.').is_none() {
+            break;
+        }
+
+        label.push('.');
+    }
+
+    let offset = len - input.len();
+
+    match input.peek() {
+        Some(&&token) if !is_terminator(token, kind) => Err((
+            Span::new(offset, offset + 1),
+            LabelError::InvalidCharacter { got: token },
+        )),
+        _ => Ok(label),
+    }
+}
+
+fn is_identifier_token(token: u8) -> bool {
+    token.is_ascii_alphanumeric() || token == b'-'
+}
+
+// Tokens which may follow an identifier: the next identifier, or the build metadata.
+fn is_terminator(token: u8, kind: Kind) -> bool {
+    token == b'.' || (matches!(kind, Kind::Prerelease) && token == b'+')
+}
+
+fn has_leading_zero(identifier: &str) -> bool {
+    identifier.len() > 1
+        && identifier.starts_with('0')
+        && identifier.bytes().all(|token| token.is_ascii_digit())
+}
@@ -0,0 +1,486 @@
+//! The _lenient parser_ module.
+//!
+//! The lenient parser accepts some commonly seen deviations from a strict `major.minor(.patch)`
+//! version, by normalizing the input before it is parsed by the [`original::Parser`]:
+//!
+//! * surrounding whitespace is removed, e.g. ` 1.2.3\n` is parsed as `1.2.3`;
+//! * a `v` or `V` prefix is removed, e.g. `v1.2` is parsed as `1.2`;
+//! * leading zeros are removed from the components, e.g. `22.04` is parsed as `22.4`.
+//!
+//! The applied normalizations are reported, so a caller may warn about them, instead of rejecting
+//! the input.
+//!
+//! The strict parsers remain the default, e.g. for [`Version::parse`].
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::parsers::lenient::{LenientParser, Normalization};
+//! use version_number::{Component, Version};
+//!
+//! let normalized = LenientParser.parse_normalized("v22.04\n").unwrap();
+//!
+//! assert_eq!(normalized.value, Version::new_base_version(22, 4));
+//! assert_eq!(
+//!     normalized.normalizations,
+//!     vec![
+//!         Normalization::Whitespace,
+//!         Normalization::Prefix { prefix: 'v' },
+//!         Normalization::LeadingZeros { component: Component::Minor },
+//!     ]
+//! );
+//! ```
+//!
+//! [`original::Parser`]: crate::parsers::original::Parser
+//! [`Version::parse`]: crate::Version::parse
+
+use crate::parsers::modular::component::starts_with_patch;
+use crate::parsers::original::OriginalParser;
+use crate::parsers::{BaseVersionParser, FullVersionParser, Prefix, Span, VersionParser};
+use crate::{BaseVersion, Component, FullVersion, ParserError, Version};
+use std::fmt;
+
+/// A parser which normalizes commonly seen deviations of a version, before parsing it.
+///
+/// The normalizations applied are only reported by the `parse_*_normalized` methods. The
+/// [`VersionParser`], [`BaseVersionParser`] and [`FullVersionParser`] implementations discard
+/// them.
+///
+/// When parsing a prefix, leading whitespace and a `v` prefix are skipped, and the components are
+/// parsed greedily, so zero-padded components are accepted, but trailing whitespace is part of
+/// the remainder.
+///
+/// The span of a [`ParserError`] refers to the input as it was given, and not to the normalized
+/// input.
+#[derive(Debug)]
+pub struct LenientParser;
+
+impl LenientParser {
+    /// Parse a two- or three-component version, and report the applied normalizations.
+    pub fn parse_normalized<B: AsRef<[u8]>>(
+        &self,
+        input: B,
+    ) -> Result<Normalized<Version>, ParserError> {
+        let (normalized, origins) = normalize(input.as_ref());
+
+        normalized
+            .try_map(|input| OriginalParser.parse_version(input))
+            .map_err(|error| origins.remap(error))
+    }
+
+    /// Parse a two-component version, and report the applied normalizations.
+    pub fn parse_base_normalized<B: AsRef<[u8]>>(
+        &self,
+        input: B,
+    ) -> Result<Normalized<BaseVersion>, ParserError> {
+        let (normalized, origins) = normalize(input.as_ref());
+
+        normalized
+            .try_map(|input| OriginalParser.parse_base(input))
+            .map_err(|error| origins.remap(error))
+    }
+
+    /// Parse a three-component version, and report the applied normalizations.
+    pub fn parse_full_normalized<B: AsRef<[u8]>>(
+        &self,
+        input: B,
+    ) -> Result<Normalized<FullVersion>, ParserError> {
+        let (normalized, origins) = normalize(input.as_ref());
+
+        normalized
+            .try_map(|input| OriginalParser.parse_full(input))
+            .map_err(|error| origins.remap(error))
+    }
+}
+
+impl VersionParser for LenientParser {
+    fn parse_version<B: AsRef<[u8]>>(&self, input: B) -> Result<Version, ParserError> {
+        self.parse_normalized(input)
+            .map(|normalized| normalized.value)
+    }
+
+    fn parse_version_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, Version>, ParserError> {
+        let end = prefix_end(input, 3);
+
+        self.parse_version(&input[..end])
+            .map(|value| Prefix::new(value, input, end))
+    }
+}
+
+impl BaseVersionParser for LenientParser {
+    fn parse_base<B: AsRef<[u8]>>(&self, input: B) -> Result<BaseVersion, ParserError> {
+        self.parse_base_normalized(input)
+            .map(|normalized| normalized.value)
+    }
+
+    fn parse_base_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, BaseVersion>, ParserError> {
+        let end = prefix_end(input, 2);
+
+        self.parse_base(&input[..end])
+            .map(|value| Prefix::new(value, input, end))
+    }
+}
+
+impl FullVersionParser for LenientParser {
+    fn parse_full<B: AsRef<[u8]>>(&self, input: B) -> Result<FullVersion, ParserError> {
+        self.parse_full_normalized(input)
+            .map(|normalized| normalized.value)
+    }
+
+    fn parse_full_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, FullVersion>, ParserError> {
+        let end = prefix_end(input, 3);
+
+        self.parse_full(&input[..end])
+            .map(|value| Prefix::new(value, input, end))
+    }
+}
+
+/// A parsed value, together with the normalizations which were applied to the input to parse it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Normalized<T> {
+    /// The parsed value.
+    pub value: T,
+    /// The normalizations which were applied, in the order in which they were applied.
+    pub normalizations: Vec<Normalization>,
+}
+
+impl<T> Normalized<T> {
+    /// Whether the input had to be normalized before it could be parsed, i.e. whether the input
+    /// would have been rejected by a strict parser.
+    pub fn is_normalized(&self) -> bool {
+        !self.normalizations.is_empty()
+    }
+
+    fn try_map<U, E, F>(self, f: F) -> Result<Normalized<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        Ok(Normalized {
+            value: f(self.value)?,
+            normalizations: self.normalizations,
+        })
+    }
+}
+
+/// A normalization which may be applied by the [`LenientParser`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Normalization {
+    /// Leading and/or trailing whitespace was removed.
+    Whitespace,
+    /// A `v` or `V` prefix was removed.
+    Prefix {
+        /// The prefix which was removed.
+        prefix: char,
+    },
+    /// Leading zeros were removed from a component, e.g. `04` became `4`.
+    LeadingZeros {
+        /// The component from which the leading zeros were removed.
+        component: Component,
+    },
+}
+
+impl fmt::Display for Normalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Whitespace => f.write_str("removed surrounding whitespace"),
+            Self::Prefix { prefix } => write!(f, "removed the '{}' prefix", prefix),
+            Self::LeadingZeros { component } => {
+                write!(f, "removed leading zeros from the {} component", component)
+            }
+        }
+    }
+}
+
+// Normalize the input, and map each byte of the normalized input to its origin in the input.
+fn normalize(input: &[u8]) -> (Normalized<Vec<u8>>, Origins) {
+    let mut normalizations = Vec::new();
+
+    let (start, end) = trim(input);
+    if end - start != input.len() {
+        normalizations.push(Normalization::Whitespace);
+    }
+
+    let start = match input[start..end] {
+        [prefix @ (b'v' | b'V'), ..] => {
+            normalizations.push(Normalization::Prefix {
+                prefix: char::from(prefix),
+            });
+            start + 1
+        }
+        _ => start,
+    };
+
+    let components = [Component::Major, Component::Minor, Component::Patch];
+    let mut value = Vec::with_capacity(end - start);
+    let mut origins = Origins {
+        spans: Vec::with_capacity(end - start),
+        end,
+    };
+    let mut part_start = start;
+
+    for (i, part) in input[start..end].split(|&b| b == b'.').enumerate() {
+        if i > 0 {
+            value.push(b'.');
+            origins.spans.push(Span::new(part_start - 1, part_start));
+        }
+
+        let is_padded = part.len() > 1 && part[0] == b'0' && part.iter().all(u8::is_ascii_digit);
+
+        // The offset of the first byte of the part which is kept.
+        let kept = match components.get(i) {
+            Some(&component) if is_padded => {
+                normalizations.push(Normalization::LeadingZeros { component });
+
+                // Keep the last digit, so a component which consists of only zeros becomes `0`.
+                part.iter()
+                    .position(|&b| b != b'0')
+                    .unwrap_or(part.len() - 1)
+            }
+            _ => 0,
+        };
+
+        value.extend_from_slice(&part[kept..]);
+        origins
+            .spans
+            .extend((part_start + kept..part_start + part.len()).map(|at| {
+                // The first byte which is kept also originates from the removed leading zeros.
+                let from = if at == part_start + kept {
+                    part_start
+                } else {
+                    at
+                };
+                Span::new(from, at + 1)
+            }));
+
+        part_start += part.len() + 1;
+    }
+
+    let normalized = Normalized {
+        value,
+        normalizations,
+    };
+
+    (normalized, origins)
+}
+
+// The span of the input from which each byte of the normalized input originates.
+struct Origins {
+    spans: Vec<Span>,
+    // The offset in the input of the end of the normalized input.
+    end: usize,
+}
+
+impl Origins {
+    // Map the span of an error in the normalized input to the span in the input.
+    fn remap(&self, error: ParserError) -> ParserError {
+        let span = error.span();
+        let start = self
+            .spans
+            .get(span.start)
+            .map_or(self.end, |origin| origin.start);
+
+        let span = match span
+            .end
+            .checked_sub(1)
+            .and_then(|last| self.spans.get(last))
+        {
+            Some(last) if !span.is_empty() => Span::new(start, last.end),
+            _ => Span::empty(start),
+        };
+
+        error.with_span(span)
+    }
+}
+
+// The end of the greedy prefix of the input, which consists of leading whitespace, an optional
+// `v` prefix, and at most the given number of `components`, each of which is a sequence of
+// digits, which may be zero-padded.
+fn prefix_end(input: &[u8], components: usize) -> usize {
+    let digits = |from: usize| {
+        input[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut end = input
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(input.len());
+
+    if matches!(input.get(end), Some(b'v' | b'V')) {
+        end += 1;
+    }
+
+    end += digits(end);
+
+    for _ in 1..components {
+        if !starts_with_patch(&input[end..]) {
+            break;
+        }
+
+        end += 1 + digits(end + 1);
+    }
+
+    end
+}
+
+// The start and end offsets of the input without surrounding whitespace.
+fn trim(input: &[u8]) -> (usize, usize) {
+    let start = input
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    let end = input
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |end| end + 1);
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{NumericError, Span};
+
+    #[yare::parameterized(
+        strict_base = { "1.2", Version::new_base_version(1, 2), &[] },
+        strict_full = { "1.2.3", Version::new_full_version(1, 2, 3), &[] },
+        zero = { "0.0.0", Version::new_full_version(0, 0, 0), &[] },
+        prefix = { "v1.2", Version::new_base_version(1, 2), &[Normalization::Prefix { prefix: 'v' }] },
+        uppercase_prefix = { "V1.2.3", Version::new_full_version(1, 2, 3), &[Normalization::Prefix { prefix: 'V' }] },
+        whitespace = { " 1.2.3\n", Version::new_full_version(1, 2, 3), &[Normalization::Whitespace] },
+        leading_zero_minor = { "22.04", Version::new_base_version(22, 4), &[Normalization::LeadingZeros { component: Component::Minor }] },
+        leading_zero_all = { "01.02.03", Version::new_full_version(1, 2, 3), &[
+            Normalization::LeadingZeros { component: Component::Major },
+            Normalization::LeadingZeros { component: Component::Minor },
+            Normalization::LeadingZeros { component: Component::Patch },
+        ] },
+        only_zeros = { "1.000", Version::new_base_version(1, 0), &[Normalization::LeadingZeros { component: Component::Minor }] },
+        all = { "\tv2023.07.0 ", Version::new_full_version(2023, 7, 0), &[
+            Normalization::Whitespace,
+            Normalization::Prefix { prefix: 'v' },
+            Normalization::LeadingZeros { component: Component::Minor },
+        ] },
+    )]
+    fn parse_normalized(input: &str, expected: Version, normalizations: &[Normalization]) {
+        let normalized = LenientParser.parse_normalized(input).unwrap();
+
+        assert_eq!(normalized.value, expected);
+        assert_eq!(normalized.normalizations, normalizations);
+        assert_eq!(normalized.is_normalized(), !normalizations.is_empty());
+    }
+
+    #[yare::parameterized(
+        empty = { "" },
+        whitespace_only = { " \n" },
+        prefix_only = { "v" },
+        double_prefix = { "vv1.2" },
+        inner_whitespace = { "1. 2" },
+        four_components = { "1.2.3.04" },
+        label = { "v1.2.3-beta" },
+    )]
+    fn rejected(input: &str) {
+        assert!(LenientParser.parse_version(input).is_err());
+    }
+
+    #[test]
+    fn base() {
+        let normalized = LenientParser.parse_base_normalized("v1.02").unwrap();
+
+        assert_eq!(normalized.value, BaseVersion::new(1, 2));
+        assert_eq!(normalized.normalizations.len(), 2);
+        assert!(LenientParser.parse_base("v1.2.3").is_err());
+    }
+
+    #[test]
+    fn full() {
+        let normalized = LenientParser.parse_full_normalized(" 1.2.03").unwrap();
+
+        assert_eq!(normalized.value, FullVersion::new(1, 2, 3));
+        assert_eq!(normalized.normalizations.len(), 2);
+        assert!(LenientParser.parse_full("v1.2").is_err());
+    }
+
+    #[test]
+    fn overflow_after_normalization() {
+        let err = LenientParser
+            .parse_version("1.018446744073709551616")
+            .unwrap_err();
+
+        // The span includes the removed leading zero.
+        assert_eq!(*err.reason(), NumericError::Overflow.into());
+        assert_eq!(err.span(), Span::new(2, 23));
+    }
+
+    #[yare::parameterized(
+        unexpected_token = { "  v1.02.x", Span::new(8, 9) },
+        leading_zeros = { "v001.2.x", Span::new(7, 8) },
+        non_ascii = { " 1.2é", Span::new(4, 6) },
+        end_of_input = { "v1.02. \n", Span::empty(6) },
+        trailing = { "1.2.3.04", Span::new(5, 6) },
+        number = { "\tv1.2.0018446744073709551616", Span::new(6, 28) },
+    )]
+    fn span_in_original_input(input: &str, span: Span) {
+        let err = LenientParser.parse_version(input).unwrap_err();
+
+        assert_eq!(err.span(), span);
+    }
+
+    #[yare::parameterized(
+        strict = { "1.2.3 rest", Version::new_full_version(1, 2, 3), " rest" },
+        prefix = { "v1.2.tar.gz", Version::new_base_version(1, 2), ".tar.gz" },
+        leading_whitespace = { "\tV1.2.3\n", Version::new_full_version(1, 2, 3), "\n" },
+        leading_zeros = { "22.04-beta", Version::new_base_version(22, 4), "-beta" },
+    )]
+    fn parse_prefix(input: &str, version: Version, remainder: &str) {
+        let prefix = LenientParser
+            .parse_version_prefix(input.as_bytes())
+            .unwrap();
+
+        assert_eq!(prefix.value, version);
+        assert_eq!(prefix.remainder, remainder.as_bytes());
+        assert_eq!(prefix.consumed, input.len() - remainder.len());
+    }
+
+    #[yare::parameterized(
+        padded_minor = { "  v1.02.x", Span::new(8, 9) },
+        padded_major = { " V01.2.x", Span::new(7, 8) },
+        overflow = { "v1.018446744073709551616.0", Span::new(3, 24) },
+    )]
+    fn trait_span_in_original_input(input: &str, span: Span) {
+        let version = LenientParser.parse_version(input).unwrap_err();
+        let full = LenientParser.parse_full(input).unwrap_err();
+
+        assert_eq!(version.span(), span);
+        assert_eq!(full.span(), span);
+    }
+
+    #[yare::parameterized(
+        version = { LenientParser.parse_version_prefix(b"  v01.x").map(drop), Span::empty(5) },
+        base = { LenientParser.parse_base_prefix(b"\tV002-beta").map(drop), Span::empty(5) },
+        full = { LenientParser.parse_full_prefix(b" v1.02 rest").map(drop), Span::empty(6) },
+    )]
+    fn prefix_span_in_original_input(result: Result<(), ParserError>, span: Span) {
+        assert_eq!(result.unwrap_err().span(), span);
+    }
+
+    #[yare::parameterized(
+        whitespace = { Normalization::Whitespace, "removed surrounding whitespace" },
+        prefix = { Normalization::Prefix { prefix: 'v' }, "removed the 'v' prefix" },
+        leading_zeros = { Normalization::LeadingZeros { component: Component::Patch }, "removed leading zeros from the patch component" },
+    )]
+    fn display(normalization: Normalization, expected: &str) {
+        assert_eq!(normalization.to_string(), expected);
+    }
+}
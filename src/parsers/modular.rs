@@ -5,13 +5,14 @@
 //!
 //! [`crate::parsers`]
 
-use crate::parsers::{BaseVersionParser, FullVersionParser, VersionParser};
+use crate::parsers::{BaseVersionParser, FullVersionParser, Prefix, VersionParser};
 use crate::{BaseVersion, FullVersion, ParserError, Version};
 
 pub use error::{ModularParserError, NumberError};
 pub use parser::{ParsedBase, ParsedFull, ParsedState, Parser, Unparsed};
 
-mod component;
+pub(crate) mod component;
+pub(crate) mod constant;
 mod error;
 mod parser;
 mod take_while_peekable;
@@ -29,30 +30,64 @@ pub struct ModularParser;
 
 impl VersionParser for ModularParser {
     fn parse_version<B: AsRef<[u8]>>(&self, input: B) -> Result<Version, ParserError> {
-        let parser = Parser::from_slice(input.as_ref());
+        let input = input.as_ref();
+        let parser = Parser::from_slice(input);
 
-        parser.parse().map_err(ParserError::from)
+        parser
+            .parse()
+            .map_err(|e| ParserError::from(e).decode_got(input))
+    }
+
+    fn parse_version_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, Version>, ParserError> {
+        Parser::from_slice(input)
+            .parse_prefix()
+            .map_err(|e| ParserError::from(e).decode_got(input))
     }
 }
 
 impl BaseVersionParser for ModularParser {
     fn parse_base<B: AsRef<[u8]>>(&self, input: B) -> Result<BaseVersion, ParserError> {
-        let parser = Parser::from_slice(input.as_ref());
+        let input = input.as_ref();
+        let parser = Parser::from_slice(input);
 
         parser
             .parse_base()
             .and_then(|parser| parser.finish_base_version())
-            .map_err(ParserError::from)
+            .map_err(|e| ParserError::from(e).decode_got(input))
+    }
+
+    fn parse_base_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, BaseVersion>, ParserError> {
+        Parser::from_slice(input)
+            .parse_base()
+            .map(|parser| parser.finish_prefix())
+            .map_err(|e| ParserError::from(e).decode_got(input))
     }
 }
 
 impl FullVersionParser for ModularParser {
     fn parse_full<B: AsRef<[u8]>>(&self, input: B) -> Result<FullVersion, ParserError> {
-        let parser = Parser::from_slice(input.as_ref());
+        let input = input.as_ref();
+        let parser = Parser::from_slice(input);
 
         parser
             .parse_full()
             .and_then(|parser| parser.finish_full_version())
-            .map_err(ParserError::from)
+            .map_err(|e| ParserError::from(e).decode_got(input))
+    }
+
+    fn parse_full_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, FullVersion>, ParserError> {
+        Parser::from_slice(input)
+            .parse_full()
+            .map(|parser| parser.finish_prefix())
+            .map_err(|e| ParserError::from(e).decode_got(input))
     }
 }
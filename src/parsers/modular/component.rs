@@ -1,6 +1,7 @@
 use crate::parsers::modular::error::{ModularParserError, NumberError};
 use crate::parsers::modular::take_while_peekable::TakeWhilePeekable;
-use std::iter::Peekable;
+use crate::parsers::{Span, TokenSet};
+use core::iter::Peekable;
 
 /// Parse a single component of a version. A component is the number value which is separated by the
 /// dot values. For example, the version `1.22` consists of two components; the major component with
@@ -9,26 +10,49 @@ use std::iter::Peekable;
 ///
 /// A component value must be `0`, or start with a token with value `1` up to and including `9`.
 /// For example, the values `0`, `1`, `39`, `90` are all valid, while `00`, `01`, `09273` are not.
+///
+/// The `len` is the length of the complete input, which is used to compute the span of an error
+/// from the remaining length of the iterator.
 #[allow(clippy::manual_try_fold)]
 pub fn parse_component<'b>(
-    input: &mut Peekable<impl Iterator<Item = &'b u8>>,
+    input: &mut Peekable<impl ExactSizeIterator<Item = &'b u8>>,
+    len: usize,
 ) -> Result<u64, ModularParserError> {
-    input.take_while_peekable(|&tok| tok.is_ascii_digit()).fold(
-        Err(ModularParserError::ExpectedNumericToken { got: None }),
-        |state: Result<u64, ModularParserError>, next| {
+    let start = len - input.len();
+
+    let value = input.take_while_peekable(|&tok| tok.is_ascii_digit()).fold(
+        Ok(None),
+        |state: Result<Option<u64>, NumberError>, next| {
             let next = u64::from(next - b'0');
 
             match state {
-                Ok(0) => Err(ModularParserError::NumberError(NumberError::LeadingZero)),
-                Ok(value) => value
+                Ok(None) => Ok(Some(next)),
+                Ok(Some(0)) => Err(NumberError::LeadingZero),
+                Ok(Some(value)) => value
                     .checked_mul(10)
                     .and_then(|lhs| lhs.checked_add(next))
-                    .ok_or(ModularParserError::NumberError(NumberError::Overflow)),
-                Err(ModularParserError::ExpectedNumericToken { got: None }) => Ok(next),
+                    .map(Some)
+                    .ok_or(NumberError::Overflow),
                 Err(err) => Err(err),
             }
         },
-    )
+    );
+
+    match value {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) => {
+            let got = input.peek().map(|&&token| token);
+
+            Err(ModularParserError::ExpectedNumericToken {
+                span: Span::token(start, got),
+                got,
+            })
+        }
+        Err(error) => Err(ModularParserError::NumberError {
+            span: Span::new(start, len - input.len()),
+            error,
+        }),
+    }
 }
 
 /// Peeks at the next token in the iterator and checks whether the token is the `.` character.
@@ -40,21 +64,60 @@ pub fn peek_is_dot<'b>(input: &mut Peekable<impl Iterator<Item = &'b u8>>) -> bo
 
 /// Consumes the next element of the iterator and checks whether the value is the character `.`.
 /// If this holds, then the value `Ok(())` will be returned.
-/// If there is no next character, i.e. the iterator returns `None`, or the token returned is not   
-/// the character `.`, a `Err(ParseError::ExpectedSeparator)` will be returned.
-pub fn parse_dot<'b>(input: &mut impl Iterator<Item = &'b u8>) -> Result<(), ModularParserError> {
-    input
-        .next()
-        .filter(|&&token| token == b'.')
-        .map(|_| ())
-        .ok_or(ModularParserError::ExpectedSeparator { got: None })
+/// If there is no next character, i.e. the iterator returns `None`, or the token returned is not
+/// the character `.`, a `Err(ParseError::ExpectedSeparator)` will be returned, with the given
+/// set of `expected` tokens.
+pub fn parse_dot<'b>(
+    input: &mut impl ExactSizeIterator<Item = &'b u8>,
+    len: usize,
+    expected: TokenSet,
+) -> Result<(), ModularParserError> {
+    let at = len - input.len();
+
+    match input.next() {
+        Some(&b'.') => Ok(()),
+        got => Err(ModularParserError::ExpectedSeparator {
+            span: Span::token(at, got),
+            got: got.copied(),
+            expected,
+        }),
+    }
 }
 
 /// Consumes the next element of the iterator, and returns `Ok(())` if there isn't any next value,
-/// or `Err(ParseError::ExpectedEOI)` if there is.
-pub fn is_done<'b>(input: &mut impl Iterator<Item = &'b u8>) -> Result<(), ModularParserError> {
+/// or `Err(ParseError::ExpectedEOI)` if there is, with the given set of `expected` tokens.
+pub fn is_done<'b>(
+    input: &mut impl ExactSizeIterator<Item = &'b u8>,
+    len: usize,
+    expected: TokenSet,
+) -> Result<(), ModularParserError> {
+    let at = len - input.len();
+
     match input.next() {
-        Some(&token) => Err(ModularParserError::ExpectedEndOfInput { got: token }),
+        Some(&token) => Err(ModularParserError::ExpectedEndOfInput {
+            span: Span::new(at, at + 1),
+            got: token,
+            expected,
+        }),
         None => Ok(()),
     }
 }
+
+/// Parse a single component from the start of the `input`, and return its value together with
+/// the number of bytes which were consumed.
+///
+/// Unlike [`parse_component`], this function takes a slice, for parsers which track their
+/// position themselves, like the combinators of the `nom` and `winnow` modules.
+#[cfg(any(feature = "nom", feature = "winnow"))]
+pub fn parse_component_prefix(input: &[u8]) -> Result<(u64, usize), ModularParserError> {
+    let mut iter = input.iter().peekable();
+    let value = parse_component(&mut iter, input.len())?;
+
+    Ok((value, input.len() - iter.len()))
+}
+
+/// Checks whether the `input` starts with a separator followed by a digit, i.e. whether a greedy
+/// parser would continue with the patch component.
+pub fn starts_with_patch(input: &[u8]) -> bool {
+    matches!(input, [b'.', digit, ..] if digit.is_ascii_digit())
+}
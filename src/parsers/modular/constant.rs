@@ -0,0 +1,203 @@
+//! The _modular parser_, for `const` contexts.
+//!
+//! Iterators and the `?` operator can't be used in a `const fn`, so these functions index into
+//! the input instead. They follow the same rules, and report the same errors, as the
+//! [`Parser`](super::Parser).
+
+use super::error::{ModularParserError, NumberError};
+use crate::parsers::error::expected;
+use crate::parsers::{Span, TokenSet};
+use crate::{BaseVersion, FullVersion, Version};
+
+// Like the `?` operator, which can't be used in a `const fn`.
+macro_rules! tri {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+/// Parse a two component `major.minor` version, and check that there is no remaining input.
+pub const fn parse_base(input: &[u8]) -> Result<BaseVersion, ModularParserError> {
+    let (version, at) = tri!(parse_base_prefix(input));
+    tri!(is_done(input, at, expected::END));
+
+    Ok(version)
+}
+
+/// Parse a three component `major.minor.patch` version, and check that there is no remaining
+/// input.
+pub const fn parse_full(input: &[u8]) -> Result<FullVersion, ModularParserError> {
+    let (base, at) = tri!(parse_base_prefix(input));
+    let at = tri!(parse_dot(input, at, expected::SEPARATOR));
+    let (patch, at) = tri!(parse_component(input, at));
+    tri!(is_done(input, at, expected::END));
+
+    Ok(FullVersion::new(base.major, base.minor, patch))
+}
+
+/// Parse a two or three component version, and check that there is no remaining input.
+pub const fn parse_version(input: &[u8]) -> Result<Version, ModularParserError> {
+    let (base, at) = tri!(parse_base_prefix(input));
+
+    if let Some(b'.') = peek(input, at) {
+        let (patch, at) = tri!(parse_component(input, at + 1));
+        tri!(is_done(input, at, expected::END));
+
+        Ok(Version::new_full_version(base.major, base.minor, patch))
+    } else {
+        tri!(is_done(input, at, expected::SEPARATOR_OR_END));
+
+        Ok(Version::Base(base))
+    }
+}
+
+// Parse the `major.minor` components, and return the version with the offset after it.
+const fn parse_base_prefix(input: &[u8]) -> Result<(BaseVersion, usize), ModularParserError> {
+    let (major, at) = tri!(parse_component(input, 0));
+    let at = tri!(parse_dot(input, at, expected::SEPARATOR));
+    let (minor, at) = tri!(parse_component(input, at));
+
+    Ok((BaseVersion::new(major, minor), at))
+}
+
+// Parse the component which starts at the offset `start`, and return its value with the offset
+// after it. Like `component::parse_component`, all digits are consumed, even after an error.
+const fn parse_component(input: &[u8], start: usize) -> Result<(u64, usize), ModularParserError> {
+    let mut at = start;
+    let mut value: u64 = 0;
+    let mut error = None;
+
+    while at < input.len() && input[at].is_ascii_digit() {
+        let digit = (input[at] - b'0') as u64;
+
+        if error.is_none() {
+            if at > start && value == 0 {
+                error = Some(NumberError::LeadingZero);
+            } else {
+                match value.checked_mul(10) {
+                    Some(lhs) => match lhs.checked_add(digit) {
+                        Some(next) => value = next,
+                        None => error = Some(NumberError::Overflow),
+                    },
+                    None => error = Some(NumberError::Overflow),
+                }
+            }
+        }
+
+        at += 1;
+    }
+
+    if at == start {
+        let got = peek(input, start);
+
+        return Err(ModularParserError::ExpectedNumericToken {
+            span: token_span(start, got),
+            got,
+        });
+    }
+
+    match error {
+        Some(error) => Err(ModularParserError::NumberError {
+            span: Span::new(start, at),
+            error,
+        }),
+        None => Ok((value, at)),
+    }
+}
+
+// Parse the `.` separator at the offset `at`, and return the offset after it.
+const fn parse_dot(
+    input: &[u8],
+    at: usize,
+    expected: TokenSet,
+) -> Result<usize, ModularParserError> {
+    match peek(input, at) {
+        Some(b'.') => Ok(at + 1),
+        got => Err(ModularParserError::ExpectedSeparator {
+            span: token_span(at, got),
+            got,
+            expected,
+        }),
+    }
+}
+
+// Check that the offset `at` is the end of the input.
+const fn is_done(input: &[u8], at: usize, expected: TokenSet) -> Result<(), ModularParserError> {
+    match peek(input, at) {
+        Some(got) => Err(ModularParserError::ExpectedEndOfInput {
+            span: Span::new(at, at + 1),
+            got,
+            expected,
+        }),
+        None => Ok(()),
+    }
+}
+
+const fn peek(input: &[u8], at: usize) -> Option<u8> {
+    if at < input.len() {
+        Some(input[at])
+    } else {
+        None
+    }
+}
+
+// Like `Span::token`, which is generic, and so can't be a `const fn`.
+const fn token_span(at: usize, got: Option<u8>) -> Span {
+    match got {
+        Some(_) => Span::new(at, at + 1),
+        None => Span::empty(at),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::modular::Parser;
+
+    #[yare::parameterized(
+        base = { "1.2" },
+        full = { "1.2.3" },
+        max = { "18446744073709551615.0.0" },
+        zeros = { "0.0.0" },
+        empty = { "" },
+        major_only = { "1" },
+        trailing_dot = { "1.2." },
+        four = { "1.2.3.4" },
+        label = { "1.2.3-beta" },
+        whitespace = { "1.2 " },
+        not_a_number = { "a.b" },
+        leading_zero = { "01.2" },
+        leading_zeros = { "1.002" },
+        double_zero = { "1.00" },
+        overflow = { "18446744073709551616.0" },
+        overflow_then_more_digits = { "184467440737095516160000.0" },
+        non_ascii = { "1.é" },
+    )]
+    fn same_as_modular_parser(input: &str) {
+        let bytes = input.as_bytes();
+
+        assert_eq!(parse_version(bytes), Parser::from_slice(bytes).parse());
+        assert_eq!(
+            parse_base(bytes),
+            Parser::from_slice(bytes)
+                .parse_base()
+                .and_then(|parser| parser.finish_base_version())
+        );
+        assert_eq!(
+            parse_full(bytes),
+            Parser::from_slice(bytes)
+                .parse_full()
+                .and_then(|parser| parser.finish_full_version())
+        );
+    }
+
+    #[test]
+    fn const_context() {
+        const VERSION: Result<Version, ModularParserError> = parse_version(b"1.56");
+
+        assert_eq!(VERSION, Ok(Version::new_base_version(1, 56)));
+    }
+}
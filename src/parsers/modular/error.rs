@@ -1,94 +1,193 @@
-use crate::parsers::error::ExpectedError;
-use crate::parsers::NumericError;
+use crate::parsers::error::{expected, ExpectedError};
+use crate::parsers::render::Byte;
+#[cfg(feature = "std")]
+use crate::parsers::render::Snippet;
+use crate::parsers::{LabelError, NumericError, Span, TokenSet};
 use crate::ParserError;
+use core::fmt;
 
 /// Errors which may be returned during parsing, by the _modular parser_.
-#[derive(Clone, Debug, thiserror::Error, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ModularParserError {
-    // /// Expected input token(s) for currently being parsed token, but got nothing.
-    // NoInputForComponent,
-    // /// Expected end of input, but got more tokens.
-    // ExpectedEOI,
     /// When this error variant is returned, the parser expected that no more
     /// tokens should be present, but instead 1 or more additional tokens
     /// were not parsed yet.
-    ///
-    #[error("Expected end of input after parsing third version number component, but got: '{}'", char::from(*.got))]
     ExpectedEndOfInput {
+        /// The span of the additional token.
+        span: Span,
         /// An additional token still present when the parser was expected to have
         /// reached the end-of-input for the given input.
         got: u8,
+        /// The set of tokens which would have been accepted instead.
+        expected: TokenSet,
     },
 
     /// When this error variant is returned, the '.' token was expected, but
     /// a different token was present, or the end-of-input reached.
     ///
     /// The `got` field shows the token read.
-    #[error(
-        "Expected the dot-separator '.', but got '{}'",
-        .got.map(|c| String::from(char::from(c))).unwrap_or_else(|| "EOI".to_string()),
-    )]
     ExpectedSeparator {
+        /// The span of the token read, which is empty if we got the end-of-input.
+        span: Span,
         /// Token read, or `None` if we unexpectedly got the end-of-input.
         got: Option<u8>,
+        /// The set of tokens which would have been accepted instead.
+        expected: TokenSet,
     },
 
     /// When this error variant is returned, a numeric token was expected, but
     /// a different token was present, or the end-of-input reached.
-    #[error(
-        "Expected 0-9, but got '{}'",
-        .got.map(|c| String::from(char::from(c))).unwrap_or_else(|| "EOI".to_string()),
-    )]
     ExpectedNumericToken {
+        /// The span of the token read, which is empty if we got the end-of-input.
+        span: Span,
         /// Token read, or `None` if we unexpectedly got the end-of-input.
         got: Option<u8>,
     },
 
     /// An error variant for faults when parsing and constructing a number.
-    #[error(transparent)]
-    NumberError(#[from] NumberError),
+    NumberError {
+        /// The span of the number component.
+        span: Span,
+        /// The reason why the number could not be constructed.
+        error: NumberError,
+    },
+
+    /// An error variant for faults when parsing the labels of a [`LabeledVersion`].
+    ///
+    /// [`LabeledVersion`]: crate::LabeledVersion
+    LabelError {
+        /// The span of the label, or of the token within the label, which could not be parsed.
+        span: Span,
+        /// The reason why the label could not be parsed.
+        error: LabelError,
+    },
+}
+
+impl ModularParserError {
+    /// The span of the input at which parsing failed.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::ExpectedEndOfInput { span, .. }
+            | Self::ExpectedSeparator { span, .. }
+            | Self::ExpectedNumericToken { span, .. }
+            | Self::NumberError { span, .. }
+            | Self::LabelError { span, .. } => *span,
+        }
+    }
+
+    /// Render a snippet of the `input`, which underlines the [`span`] at which parsing failed.
+    ///
+    /// The modular parser does not keep the input around, so the `input` which was parsed must be
+    /// provided.
+    ///
+    /// [`span`]: ModularParserError::span
+    #[cfg(feature = "std")]
+    pub fn render<'a, B: AsRef<[u8]> + ?Sized>(&self, input: &'a B) -> Snippet<'a> {
+        let expected = match self {
+            Self::ExpectedEndOfInput { expected, .. }
+            | Self::ExpectedSeparator { expected, .. } => *expected,
+            Self::ExpectedNumericToken { .. } => expected::NUMBER,
+            Self::NumberError { .. } | Self::LabelError { .. } => TokenSet::EMPTY,
+        };
+
+        Snippet::new(input, self.span()).with_expected(expected)
+    }
+}
+
+impl fmt::Display for ModularParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpectedEndOfInput { span, got, .. } => write!(
+                f,
+                "Expected end of input after parsing the version, but got: '{}' at {}",
+                Byte(Some(*got)),
+                span
+            ),
+            Self::ExpectedSeparator { span, got, .. } => write!(
+                f,
+                "Expected the dot-separator '.', but got '{}' at {}",
+                Byte(*got),
+                span
+            ),
+            Self::ExpectedNumericToken { span, got } => {
+                write!(f, "Expected 0-9, but got '{}' at {}", Byte(*got), span)
+            }
+            Self::NumberError { span, error } => write!(f, "{} at {}", error, span),
+            Self::LabelError { span, error } => write!(f, "{} at {}", error, span),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ModularParserError {}
+
 /// An error type for faults relating to parsing and constructing numbers.
-#[derive(Clone, Debug, thiserror::Error, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum NumberError {
     /// When this error variant is returned, the parser detected that the number started with a leading
     /// zero, which is not allowed for number components.
-    #[error("Number may not start with a leading zero, unless the complete component is '0'")]
     LeadingZero,
 
     /// This error variant is returned if the number would overflow.
     ///
     /// Each number component consists of a 64 bits unsigned integer.
-    #[error("Overflow: Found number component which would be larger than the maximum supported number (max={})", u64::MAX)]
     Overflow,
 }
 
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&NumericError::from(self.clone()), f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumberError {}
+
+impl From<NumberError> for NumericError {
+    fn from(error: NumberError) -> Self {
+        match error {
+            NumberError::LeadingZero => NumericError::LeadingZero,
+            NumberError::Overflow => NumericError::Overflow,
+        }
+    }
+}
+
 impl From<ModularParserError> for ParserError {
     fn from(value: ModularParserError) -> Self {
         match value {
-            ModularParserError::ExpectedEndOfInput { got } => {
-                ParserError::Expected(ExpectedError::EndOfInput {
-                    at: None,
-                    got: char::from(got),
-                })
-            }
-            ModularParserError::ExpectedNumericToken { got } => {
-                ParserError::Expected(ExpectedError::Numeric {
-                    at: None,
-                    got: got.map(char::from),
-                })
-            }
-            ModularParserError::ExpectedSeparator { got } => {
-                ParserError::Expected(ExpectedError::Separator {
-                    at: None,
-                    got: got.map(char::from),
-                })
+            ModularParserError::ExpectedEndOfInput {
+                span,
+                got,
+                expected,
+            } => ParserError::new(
+                span,
+                ExpectedError {
+                    expected,
+                    got: ExpectedError::got_byte(Some(got)),
+                },
+            ),
+            ModularParserError::ExpectedNumericToken { span, got } => ParserError::new(
+                span,
+                ExpectedError {
+                    expected: expected::NUMBER,
+                    got: ExpectedError::got_byte(got),
+                },
+            ),
+            ModularParserError::ExpectedSeparator {
+                span,
+                got,
+                expected,
+            } => ParserError::new(
+                span,
+                ExpectedError {
+                    expected,
+                    got: ExpectedError::got_byte(got),
+                },
+            ),
+            ModularParserError::NumberError { span, error } => {
+                ParserError::new(span, NumericError::from(error))
             }
-            ModularParserError::NumberError(e) => match e {
-                NumberError::LeadingZero => ParserError::Numeric(NumericError::LeadingZero),
-                NumberError::Overflow => ParserError::Numeric(NumericError::Overflow),
-            },
+            ModularParserError::LabelError { span, error } => ParserError::new(span, error),
         }
     }
 }
@@ -1,8 +1,14 @@
-use super::component::{is_done, parse_component, parse_dot, peek_is_dot};
+use super::component::{is_done, parse_component, parse_dot, peek_is_dot, starts_with_patch};
 use super::error::ModularParserError;
-use crate::{BaseVersion, FullVersion, Version};
-use std::iter::Peekable;
-use std::slice::Iter;
+use crate::parsers::error::expected;
+#[cfg(feature = "std")]
+use crate::parsers::labels;
+use crate::parsers::{Prefix, TokenSet};
+use crate::{BaseVersion, ComponentVersion, FullVersion, Version};
+#[cfg(feature = "std")]
+use crate::{BuildMetadata, LabeledVersion, Prerelease};
+use core::iter::Peekable;
+use core::slice::Iter;
 
 // States
 
@@ -64,6 +70,9 @@ impl ParsedState for ParsedFull {}
 pub struct Parser<'p, S: ParsedState> {
     state: S,
     iter: Peekable<Iter<'p, u8>>,
+    // The complete input, to compute the position of the parser from the remaining length of
+    // the iterator.
+    input: &'p [u8],
 }
 
 impl<'p> Parser<'p, Unparsed> {
@@ -81,6 +90,7 @@ impl<'p> Parser<'p, Unparsed> {
         Parser {
             state: Unparsed,
             iter: iter.peekable(),
+            input: bytes,
         }
     }
 }
@@ -108,17 +118,20 @@ impl<'p> Parser<'p, Unparsed> {
     /// assert_eq!(base.inner_version(), &BaseVersion::new(1, 2));
     /// ```
     pub fn parse_base(self) -> Result<Parser<'p, ParsedBase>, ModularParserError> {
-        let Self { mut iter, .. } = self;
+        let Self {
+            mut iter, input, ..
+        } = self;
 
-        let major = parse_component(iter.by_ref())?;
-        parse_dot(iter.by_ref())?;
-        let minor = parse_component(iter.by_ref())?;
+        let major = parse_component(iter.by_ref(), input.len())?;
+        parse_dot(iter.by_ref(), input.len(), expected::SEPARATOR)?;
+        let minor = parse_component(iter.by_ref(), input.len())?;
 
         let version = BaseVersion::new(major, minor);
 
         Ok(Parser {
             state: ParsedBase { version },
             iter,
+            input,
         })
     }
 
@@ -188,9 +201,146 @@ impl<'p> Parser<'p, Unparsed> {
         if peek_is_dot(parser.iter.by_ref()) {
             parser.parse_patch()?.finish()
         } else {
-            parser.finish()
+            parser
+                .finish_expecting(expected::SEPARATOR_OR_END)
+                .map(Version::Base)
         }
     }
+
+    /// Parse a `base`, two component `major.minor` [`Version`], or a `full`, three component
+    /// `major.minor.patch` version, optionally followed by a pre-release label and/or build
+    /// metadata.
+    ///
+    /// Unlike [`Parser::parse`], this method accepts labels such as `-beta.3` and `+build.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::{FullVersion, Version};
+    /// use version_number::parsers::modular::Parser;
+    ///
+    /// let parser = Parser::from_slice("1.2.3-rc.1+build.5".as_bytes());
+    ///
+    /// let labeled = parser.parse_labeled().unwrap();
+    ///
+    /// assert_eq!(labeled.version, Version::Full(FullVersion::new(1, 2, 3)));
+    /// assert_eq!(labeled.pre.as_str(), "rc.1");
+    /// assert_eq!(labeled.build.as_str(), "build.5");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_labeled(self) -> Result<LabeledVersion, ModularParserError> {
+        let mut parser = self.parse_base()?;
+
+        if peek_is_dot(parser.iter.by_ref()) {
+            parser.parse_patch()?.finish_labeled()
+        } else {
+            parser.finish_labeled_expecting(expected::SEPARATOR_LABEL_OR_END)
+        }
+    }
+
+    /// Parse a `base`, two component `major.minor` [`Version`], or a `full`, three component
+    /// `major.minor.patch` version, from the start of the input, and return it together with
+    /// the remainder of the input.
+    ///
+    /// Parsing is greedy: the patch component is parsed if the minor component is followed by
+    /// a separator and a digit. Unlike [`Parser::parse`], remaining input is not rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::{FullVersion, Version};
+    /// use version_number::parsers::modular::Parser;
+    ///
+    /// let parser = Parser::from_slice("1.70.0 (90c541806 2023-05-31)".as_bytes());
+    ///
+    /// let prefix = parser.parse_prefix().unwrap();
+    ///
+    /// assert_eq!(prefix.value, Version::Full(FullVersion::new(1, 70, 0)));
+    /// assert_eq!(prefix.remainder, b" (90c541806 2023-05-31)");
+    /// assert_eq!(prefix.consumed, 6);
+    /// ```
+    pub fn parse_prefix(self) -> Result<Prefix<'p, Version>, ModularParserError> {
+        let parser = self.parse_base()?;
+
+        if starts_with_patch(&parser.input[parser.position()..]) {
+            Ok(parser.parse_patch()?.finish_prefix().map(Version::Full))
+        } else {
+            Ok(parser.finish_prefix().map(Version::Base))
+        }
+    }
+
+    /// Parse a version of at least `min` and at most `N` components, like the four component
+    /// `1.2.3.4`, and check that there is no remaining input.
+    ///
+    /// The components which are not present in the input are `0`, so `1.2` parses as the
+    /// [`ComponentVersion<4>`] `1.2.0.0`. At least one component is always parsed, and a `min`
+    /// which exceeds `N` requires exactly `N` components.
+    ///
+    /// Since at least one component is parsed, `N` must be at least `1`; parsing zero components
+    /// fails to compile:
+    ///
+    /// ```compile_fail
+    /// use version_number::parsers::modular::Parser;
+    ///
+    /// let version = Parser::from_slice("".as_bytes()).parse_components::<0>(0);
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::ComponentVersion;
+    /// use version_number::parsers::modular::Parser;
+    ///
+    /// let parser = Parser::from_slice("120.0.6099".as_bytes());
+    ///
+    /// let version = parser.parse_components::<4>(3).unwrap();
+    ///
+    /// assert_eq!(version, ComponentVersion::new([120, 0, 6099, 0]));
+    /// ```
+    pub fn parse_components<const N: usize>(
+        self,
+        min: usize,
+    ) -> Result<ComponentVersion<N>, ModularParserError> {
+        // Rejects `N == 0` at compile time.
+        let () = AtLeastOneComponent::<N>::ASSERT;
+
+        let Self {
+            mut iter, input, ..
+        } = self;
+
+        let mut components = [0; N];
+        let mut parsed = 0;
+
+        for component in components.iter_mut() {
+            if parsed > 0 {
+                if parsed >= min && !peek_is_dot(iter.by_ref()) {
+                    break;
+                }
+
+                parse_dot(iter.by_ref(), input.len(), expected::SEPARATOR)?;
+            }
+
+            *component = parse_component(iter.by_ref(), input.len())?;
+            parsed += 1;
+        }
+
+        let expected = if parsed < N {
+            expected::SEPARATOR_OR_END
+        } else {
+            expected::END
+        };
+
+        is_done(iter.by_ref(), input.len(), expected)?;
+
+        Ok(ComponentVersion::new(components))
+    }
+}
+
+// Fails to compile when evaluated for `N == 0`, since a version always has a first component.
+struct AtLeastOneComponent<const N: usize>;
+
+impl<const N: usize> AtLeastOneComponent<N> {
+    const ASSERT: () = assert!(N > 0, "a version must consist of at least one component");
 }
 
 impl<'p> Parser<'p, ParsedBase> {
@@ -219,16 +369,18 @@ impl<'p> Parser<'p, ParsedBase> {
             state: ParsedBase {
                 version: BaseVersion { major, minor },
             },
+            input,
         } = self;
 
-        parse_dot(iter.by_ref())?;
-        let patch = parse_component(iter.by_ref())?;
+        parse_dot(iter.by_ref(), input.len(), expected::SEPARATOR)?;
+        let patch = parse_component(iter.by_ref(), input.len())?;
 
         let version = FullVersion::new(major, minor, patch);
 
         Ok(Parser {
             state: ParsedFull { version },
             iter,
+            input,
         })
     }
 
@@ -260,11 +412,25 @@ impl<'p> Parser<'p, ParsedBase> {
     /// When there is remaining input, this method will return a [`ModularParserError::ExpectedEOI`]
     /// instead.
     pub fn finish_base_version(self) -> Result<BaseVersion, ModularParserError> {
-        let Self { mut iter, state } = self;
+        self.finish_expecting(expected::END)
+    }
 
-        is_done(iter.by_ref())?;
+    /// Parses an optional pre-release label and/or build metadata, checks that there is no
+    /// remaining input, and returns a [`LabeledVersion`], which wraps the parsed base version.
+    ///
+    /// When there is remaining input which is not part of a label, this method will return a
+    /// [`ModularParserError::ExpectedEndOfInput`] instead.
+    #[cfg(feature = "std")]
+    pub fn finish_labeled(self) -> Result<LabeledVersion, ModularParserError> {
+        self.finish_labeled_expecting(expected::LABEL_OR_END)
+    }
 
-        Ok(state.version)
+    /// Returns the parsed base version, together with the remainder of the input, which is not
+    /// checked.
+    pub fn finish_prefix(self) -> Prefix<'p, BaseVersion> {
+        let consumed = self.position();
+
+        Prefix::new(self.state.version, self.input, consumed)
     }
 
     /// Returns the so far successfully parsed version state.
@@ -273,6 +439,41 @@ impl<'p> Parser<'p, ParsedBase> {
     pub fn inner_version(&self) -> &BaseVersion {
         &self.state.version
     }
+
+    // Checks that there is no remaining input, where the `expected` tokens are those which
+    // would have been accepted instead of the remaining input.
+    fn finish_expecting(self, expected: TokenSet) -> Result<BaseVersion, ModularParserError> {
+        let Self {
+            mut iter,
+            state,
+            input,
+        } = self;
+
+        is_done(iter.by_ref(), input.len(), expected)?;
+
+        Ok(state.version)
+    }
+
+    #[cfg(feature = "std")]
+    fn finish_labeled_expecting(
+        self,
+        expected: TokenSet,
+    ) -> Result<LabeledVersion, ModularParserError> {
+        let Self {
+            mut iter,
+            state,
+            input,
+        } = self;
+
+        let (pre, build) = parse_labels(iter.by_ref(), input.len())?;
+        is_done(iter.by_ref(), input.len(), expected)?;
+
+        Ok(LabeledVersion::new(
+            Version::Base(state.version),
+            pre,
+            build,
+        ))
+    }
 }
 
 impl<'p> Parser<'p, ParsedFull> {
@@ -281,11 +482,7 @@ impl<'p> Parser<'p, ParsedFull> {
     ///
     /// When there is remaining input, this method will return a [`ModularParserError::ExpectedEOI`]
     pub fn finish(self) -> Result<Version, ModularParserError> {
-        let Self { mut iter, state } = self;
-
-        is_done(iter.by_ref())?;
-
-        Ok(Version::Full(state.version))
+        self.finish_full_version().map(Version::Full)
     }
 
     /// Checks that there is no remaining input, and returns a [`FullVersion`].
@@ -293,13 +490,48 @@ impl<'p> Parser<'p, ParsedFull> {
     /// When there is remaining input, this method will return a [`ModularParserError::ExpectedEOI`]
     /// instead.
     pub fn finish_full_version(self) -> Result<FullVersion, ModularParserError> {
-        let Self { mut iter, state } = self;
+        let Self {
+            mut iter,
+            state,
+            input,
+        } = self;
 
-        is_done(iter.by_ref())?;
+        is_done(iter.by_ref(), input.len(), expected::END)?;
 
         Ok(state.version)
     }
 
+    /// Parses an optional pre-release label and/or build metadata, checks that there is no
+    /// remaining input, and returns a [`LabeledVersion`], which wraps the parsed full version.
+    ///
+    /// When there is remaining input which is not part of a label, this method will return a
+    /// [`ModularParserError::ExpectedEndOfInput`] instead.
+    #[cfg(feature = "std")]
+    pub fn finish_labeled(self) -> Result<LabeledVersion, ModularParserError> {
+        let Self {
+            mut iter,
+            state,
+            input,
+        } = self;
+
+        let (pre, build) = parse_labels(iter.by_ref(), input.len())?;
+        is_done(iter.by_ref(), input.len(), expected::LABEL_OR_END)?;
+
+        Ok(LabeledVersion::new(
+            Version::Full(state.version),
+            pre,
+            build,
+        ))
+    }
+
+    /// Returns the parsed full version, together with the remainder of the input, which is not
+    /// checked.
+    pub fn finish_prefix(self) -> Prefix<'p, FullVersion> {
+        let consumed = self.position();
+
+        Prefix::new(self.state.version, self.input, consumed)
+    }
+
     /// Returns the so far successfully parsed version.
     ///
     /// **NB:** Unless the end of input has been reached, this version may not be valid.
@@ -308,10 +540,27 @@ impl<'p> Parser<'p, ParsedFull> {
     }
 }
 
+impl<S: ParsedState> Parser<'_, S> {
+    // The number of bytes of the input which have been consumed.
+    fn position(&self) -> usize {
+        self.input.len() - self.iter.len()
+    }
+}
+
+#[cfg(feature = "std")]
+fn parse_labels(
+    iter: &mut Peekable<Iter<'_, u8>>,
+    len: usize,
+) -> Result<(Prerelease, BuildMetadata), ModularParserError> {
+    labels::parse_labels(iter, len)
+        .map_err(|(span, error)| ModularParserError::LabelError { span, error })
+}
+
 #[cfg(test)]
 mod tests_leading_zeros {
     use super::*;
     use crate::parsers::modular::NumberError;
+    use crate::parsers::Span;
     use crate::BaseVersion;
     use yare::parameterized;
 
@@ -334,25 +583,33 @@ mod tests_leading_zeros {
     }
 
     #[parameterized(
-        no_leading_zero_component_0 = { "00.0", ModularParserError::NumberError(NumberError::LeadingZero) },
-        no_leading_zero_component_1 = { "01.0", ModularParserError::NumberError(NumberError::LeadingZero) },
-        no_leading_zero_component_2 = { "1.01", ModularParserError::NumberError(NumberError::LeadingZero) },
+        no_leading_zero_component_0 = { "00.0", Span::new(0, 2) },
+        no_leading_zero_component_1 = { "01.0", Span::new(0, 2) },
+        no_leading_zero_component_2 = { "1.01", Span::new(2, 4) },
     )]
-    fn rejected(input: &str, expected_err: ModularParserError) {
+    fn rejected(input: &str, span: Span) {
         let input = input.as_bytes();
         let err = Parser::from_slice(input)
             .parse_base()
             .and_then(|parser| parser.finish_base_version())
             .unwrap_err();
 
-        assert_eq!(err, expected_err);
+        assert_eq!(
+            err,
+            ModularParserError::NumberError {
+                span,
+                error: NumberError::LeadingZero
+            }
+        );
     }
 }
 
 #[cfg(test)]
 mod tests_parser_base {
     use super::*;
+    use crate::parsers::error::expected;
     use crate::parsers::modular::NumberError;
+    use crate::parsers::Span;
     use crate::BaseVersion;
     use yare::parameterized;
 
@@ -382,7 +639,13 @@ mod tests_parser_base {
         let parser = Parser::from_slice(input.as_bytes());
         let err = parser.parse_base().unwrap_err();
 
-        assert_eq!(err, ModularParserError::ExpectedNumericToken { got: None });
+        assert_eq!(
+            err,
+            ModularParserError::ExpectedNumericToken {
+                span: Span::empty(0),
+                got: None
+            }
+        );
     }
 
     #[test]
@@ -391,7 +654,13 @@ mod tests_parser_base {
         let parser = Parser::from_slice(input.as_bytes());
         let err = parser.parse_base().unwrap_err();
 
-        assert_eq!(err, ModularParserError::ExpectedNumericToken { got: None });
+        assert_eq!(
+            err,
+            ModularParserError::ExpectedNumericToken {
+                span: Span::empty(2),
+                got: None
+            }
+        );
     }
 
     #[test]
@@ -406,7 +675,13 @@ mod tests_parser_base {
         let parser = Parser::from_slice(input.as_bytes());
         let err = parser.parse_base().unwrap_err();
 
-        assert_eq!(err, ModularParserError::NumberError(NumberError::Overflow));
+        assert_eq!(
+            err,
+            ModularParserError::NumberError {
+                span: Span::new(0, 20),
+                error: NumberError::Overflow
+            }
+        );
     }
 
     #[test]
@@ -415,7 +690,43 @@ mod tests_parser_base {
         let parser = Parser::from_slice(input.as_bytes());
         let err = parser.parse_base().unwrap_err();
 
-        assert_eq!(err, ModularParserError::ExpectedSeparator { got: None });
+        assert_eq!(
+            err,
+            ModularParserError::ExpectedSeparator {
+                span: Span::empty(1),
+                got: None,
+                expected: expected::SEPARATOR,
+            }
+        );
+    }
+
+    #[test]
+    fn rejected_on_separator_expected_reports_token() {
+        let input = "1,2";
+        let parser = Parser::from_slice(input.as_bytes());
+        let err = parser.parse_base().unwrap_err();
+
+        assert_eq!(
+            err,
+            ModularParserError::ExpectedSeparator {
+                span: Span::new(1, 2),
+                got: Some(b','),
+                expected: expected::SEPARATOR,
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render() {
+        let input = "1\u{2024}2";
+        let parser = Parser::from_slice(input.as_bytes());
+        let err = parser.parse_base().unwrap_err();
+
+        assert_eq!(
+            err.render(input).with_source_name("input.txt").to_string(),
+            " --> input.txt, line 1, column 2\n  |\n1 | 1\u{2024}2\n  |  ^ expected 0-9 or '.'\n"
+        );
     }
 
     #[test]
@@ -424,7 +735,14 @@ mod tests_parser_base {
         let parser = Parser::from_slice(input.as_bytes());
         let err = parser.parse_base().unwrap().finish().unwrap_err();
 
-        assert_eq!(err, ModularParserError::ExpectedEndOfInput { got: b'.' });
+        assert_eq!(
+            err,
+            ModularParserError::ExpectedEndOfInput {
+                span: Span::new(3, 4),
+                got: b'.',
+                expected: expected::END,
+            }
+        );
     }
 
     #[test]
@@ -435,23 +753,172 @@ mod tests_parser_base {
 
         assert_eq!(
             err,
-            ModularParserError::NumberError(NumberError::LeadingZero)
+            ModularParserError::NumberError {
+                span: Span::new(2, 4),
+                error: NumberError::LeadingZero
+            }
         );
     }
 
     #[parameterized(
-        in_first_component_1 = { "01.9" },
-        in_first_component_2 = { "00.9" },
-        in_second_component_1 = { "9.01" },
-        in_second_component_2 = { "9.00" },
+        in_first_component_1 = { "01.9", Span::new(0, 2) },
+        in_first_component_2 = { "00.9", Span::new(0, 2) },
+        in_second_component_1 = { "9.01", Span::new(2, 4) },
+        in_second_component_2 = { "9.00", Span::new(2, 4) },
     )]
-    fn rejected_on_leading_zero_not_allowed(input: &str) {
+    fn rejected_on_leading_zero_not_allowed(input: &str, span: Span) {
         let parser = Parser::from_slice(input.as_bytes());
         let err = parser.parse_base().unwrap_err();
 
         assert_eq!(
             err,
-            ModularParserError::NumberError(NumberError::LeadingZero)
+            ModularParserError::NumberError {
+                span,
+                error: NumberError::LeadingZero
+            }
         );
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests_parser_labeled {
+    use super::*;
+    use crate::parsers::error::expected;
+    use crate::parsers::{LabelError, Span};
+    use yare::parameterized;
+
+    #[parameterized(
+        base = { "1.2", Version::new_base_version(1, 2), "", "" },
+        base_pre = { "1.2-alpha", Version::new_base_version(1, 2), "alpha", "" },
+        full_build = { "1.2.3+b.01", Version::new_full_version(1, 2, 3), "", "b.01" },
+        full_pre_build = { "1.2.3-0.a-b+c", Version::new_full_version(1, 2, 3), "0.a-b", "c" },
+    )]
+    fn accepted(input: &str, version: Version, pre: &str, build: &str) {
+        let parser = Parser::from_slice(input.as_bytes());
+        let labeled = parser.parse_labeled().unwrap();
+
+        assert_eq!(labeled.version, version);
+        assert_eq!(labeled.pre.as_str(), pre);
+        assert_eq!(labeled.build.as_str(), build);
+    }
+
+    #[parameterized(
+        empty_pre = { "1.2.3-", ModularParserError::LabelError { span: Span::empty(6), error: LabelError::EmptyIdentifier } },
+        leading_zero = { "1.2.3-00", ModularParserError::LabelError { span: Span::new(6, 8), error: LabelError::LeadingZero } },
+        invalid = { "1.2-a~", ModularParserError::LabelError { span: Span::new(5, 6), error: LabelError::InvalidCharacter { got: b'~' } } },
+        not_a_label = { "1.2.3~a", ModularParserError::ExpectedEndOfInput { span: Span::new(5, 6), got: b'~', expected: expected::LABEL_OR_END } },
+        not_a_label_base = { "1.2~a", ModularParserError::ExpectedEndOfInput { span: Span::new(3, 4), got: b'~', expected: expected::SEPARATOR_LABEL_OR_END } },
+    )]
+    fn rejected(input: &str, expected: ModularParserError) {
+        let parser = Parser::from_slice(input.as_bytes());
+        let err = parser.parse_labeled().unwrap_err();
+
+        assert_eq!(err, expected);
+    }
+
+    #[test]
+    fn strict_rejects_labels() {
+        let parser = Parser::from_slice("1.2.3-alpha".as_bytes());
+        let err = parser.parse().unwrap_err();
+
+        assert_eq!(
+            err,
+            ModularParserError::ExpectedEndOfInput {
+                span: Span::new(5, 6),
+                got: b'-',
+                expected: expected::END,
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_parser_prefix {
+    use super::*;
+    use crate::parsers::modular::NumberError;
+    use crate::parsers::Span;
+    use yare::parameterized;
+
+    #[parameterized(
+        base = { "1.2", Version::new_base_version(1, 2), "" },
+        full = { "1.2.3", Version::new_full_version(1, 2, 3), "" },
+        archive = { "1.2.3.tar.gz", Version::new_full_version(1, 2, 3), ".tar.gz" },
+        rustc = { "1.70.0 (90c541806 2023-05-31)", Version::new_full_version(1, 70, 0), " (90c541806 2023-05-31)" },
+        four_components = { "1.2.3.4", Version::new_full_version(1, 2, 3), ".4" },
+        dot_not_followed_by_digit = { "1.2.x", Version::new_base_version(1, 2), ".x" },
+        trailing_dot = { "1.2.", Version::new_base_version(1, 2), "." },
+        label = { "1.2-beta", Version::new_base_version(1, 2), "-beta" },
+    )]
+    fn accepted(input: &str, version: Version, remainder: &str) {
+        let prefix = Parser::from_slice(input.as_bytes()).parse_prefix().unwrap();
+
+        assert_eq!(prefix.value, version);
+        assert_eq!(prefix.remainder, remainder.as_bytes());
+        assert_eq!(prefix.consumed, input.len() - remainder.len());
+    }
+
+    #[parameterized(
+        no_minor = { "1 ", ModularParserError::ExpectedSeparator { span: Span::new(1, 2), got: Some(b' '), expected: expected::SEPARATOR } },
+        leading_zero = { "1.2.03", ModularParserError::NumberError { span: Span::new(4, 6), error: NumberError::LeadingZero } },
+    )]
+    fn rejected(input: &str, expected: ModularParserError) {
+        let err = Parser::from_slice(input.as_bytes())
+            .parse_prefix()
+            .unwrap_err();
+
+        assert_eq!(err, expected);
+    }
+
+    #[test]
+    fn finish_prefix() {
+        let parser = Parser::from_slice("1.2.3-rc.1".as_bytes())
+            .parse_base()
+            .unwrap();
+
+        let prefix = parser.finish_prefix();
+
+        assert_eq!(prefix.value, BaseVersion::new(1, 2));
+        assert_eq!(prefix.remainder, b".3-rc.1");
+        assert_eq!(prefix.consumed, 3);
+    }
+}
+
+#[cfg(test)]
+mod tests_parser_components {
+    use super::*;
+    use crate::parsers::modular::NumberError;
+    use crate::parsers::Span;
+    use yare::parameterized;
+
+    #[parameterized(
+        four = { "1.2.3.4", 4, [1, 2, 3, 4] },
+        chromium = { "120.0.6099.109", 4, [120, 0, 6099, 109] },
+        three_of_four = { "1.2.3", 3, [1, 2, 3, 0] },
+        one_of_four = { "1", 1, [1, 0, 0, 0] },
+        min_zero = { "1", 0, [1, 0, 0, 0] },
+        min_exceeds_max = { "1.2.3.4", 5, [1, 2, 3, 4] },
+    )]
+    fn accepted(input: &str, min: usize, components: [u64; 4]) {
+        let version = Parser::from_slice(input.as_bytes())
+            .parse_components::<4>(min)
+            .unwrap();
+
+        assert_eq!(version, ComponentVersion::new(components));
+    }
+
+    #[parameterized(
+        empty = { "", ModularParserError::ExpectedNumericToken { span: Span::empty(0), got: None } },
+        below_min = { "1.2", ModularParserError::ExpectedSeparator { span: Span::empty(3), got: None, expected: expected::SEPARATOR } },
+        above_max = { "1.2.3.4.5", ModularParserError::ExpectedEndOfInput { span: Span::new(7, 8), got: b'.', expected: expected::END } },
+        trailing_dot = { "1.2.3.", ModularParserError::ExpectedNumericToken { span: Span::empty(6), got: None } },
+        trailing_token = { "1.2.3-beta", ModularParserError::ExpectedEndOfInput { span: Span::new(5, 6), got: b'-', expected: expected::SEPARATOR_OR_END } },
+        leading_zero = { "1.2.3.04", ModularParserError::NumberError { span: Span::new(6, 8), error: NumberError::LeadingZero } },
+    )]
+    fn rejected(input: &str, expected: ModularParserError) {
+        let err = Parser::from_slice(input.as_bytes())
+            .parse_components::<4>(3)
+            .unwrap_err();
+
+        assert_eq!(err, expected);
+    }
+}
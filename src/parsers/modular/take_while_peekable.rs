@@ -2,13 +2,16 @@
 // existence first. Since our version should always have a number component first, it is fine for
 // peekable to consume the first character, to store in the peekable iterator.
 
-use std::iter::Peekable;
+use core::iter::Peekable;
 
 pub trait TakeWhilePeekable<'peekable, I>: Iterator
 where
     I: Iterator,
 {
-    fn take_while_peekable<P>(&'peekable mut self, pred: P) -> TakeWhilePeekableImpl<I, P>
+    fn take_while_peekable<P>(
+        &'peekable mut self,
+        pred: P,
+    ) -> TakeWhilePeekableImpl<'peekable, I, P>
     where
         P: FnMut(&Self::Item) -> bool;
 }
@@ -25,7 +28,10 @@ impl<'peekable, I> TakeWhilePeekable<'peekable, I> for Peekable<I>
 where
     I: Iterator,
 {
-    fn take_while_peekable<P>(&'peekable mut self, pred: P) -> TakeWhilePeekableImpl<I, P>
+    fn take_while_peekable<P>(
+        &'peekable mut self,
+        pred: P,
+    ) -> TakeWhilePeekableImpl<'peekable, I, P>
     where
         P: FnMut(&Self::Item) -> bool,
     {
@@ -5,8 +5,8 @@
 //!
 //! [`crate::parsers`]
 
-use crate::parsers::error::ExpectedError;
-use crate::parsers::{BaseVersionParser, FullVersionParser, VersionParser};
+use crate::parsers::error::{expected, ExpectedError};
+use crate::parsers::{BaseVersionParser, FullVersionParser, Prefix, Span, VersionParser};
 use crate::{BaseVersion, FullVersion, ParserError, Version};
 pub use error::{ErrorReason, NumberError, OriginalParserError};
 pub use parser::Parser;
@@ -31,6 +31,15 @@ impl VersionParser for OriginalParser {
 
         parser.parse().map_err(ParserError::from)
     }
+
+    fn parse_version_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, Version>, ParserError> {
+        Parser::from_slice(input)
+            .parse_prefix()
+            .map_err(ParserError::from)
+    }
 }
 
 impl BaseVersionParser for OriginalParser {
@@ -41,27 +50,57 @@ impl BaseVersionParser for OriginalParser {
             .parse()
             .and_then(|v| match v {
                 Version::Base(b) => Ok(b),
-                Version::Full(f) => Err(OriginalParserError::from_parser(
-                    &parser,
-                    ErrorReason::ExpectedEndOfInput {
-                        extra_input: format!(".{}", f.patch).into_bytes(),
-                    },
-                )),
+                Version::Full(_) => {
+                    // The extra input starts at the separator which precedes the patch component.
+                    let cursor = parser
+                        .slice
+                        .iter()
+                        .rposition(|&b| b == b'.')
+                        .unwrap_or_default();
+
+                    Err(OriginalParserError::from_parser_expecting(
+                        &parser,
+                        Span::new(cursor, cursor + 1),
+                        expected::END,
+                        ErrorReason::ExpectedEndOfInput { got: b'.' },
+                    ))
+                }
             })
             .map_err(ParserError::from)
     }
+
+    fn parse_base_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, BaseVersion>, ParserError> {
+        Parser::from_slice(input)
+            .parse_base_prefix()
+            .map_err(ParserError::from)
+    }
 }
 
 impl FullVersionParser for OriginalParser {
     fn parse_full<B: AsRef<[u8]>>(&self, input: B) -> Result<FullVersion, ParserError> {
-        let parser = Parser::from_slice(input.as_ref());
+        let input = input.as_ref();
+        let parser = Parser::from_slice(input);
 
         parser.parse().map_err(From::from).and_then(|v| match v {
-            Version::Base(_) => Err(ParserError::Expected(ExpectedError::Separator {
-                at: None,
-                got: None,
-            })),
+            Version::Base(_) => Err(ParserError::new(
+                Span::empty(input.len()),
+                ExpectedError {
+                    expected: expected::SEPARATOR,
+                    got: None,
+                },
+            )),
             Version::Full(f) => Ok(f),
         })
     }
+    fn parse_full_prefix<'b>(
+        &self,
+        input: &'b [u8],
+    ) -> Result<Prefix<'b, FullVersion>, ParserError> {
+        Parser::from_slice(input)
+            .parse_full_prefix()
+            .map_err(ParserError::from)
+    }
 }
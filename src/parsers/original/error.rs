@@ -1,74 +1,133 @@
 use super::*;
 use crate::parsers::error::ExpectedError;
-use crate::parsers::NumericError;
+use crate::parsers::render::{self, Byte, Snippet};
+use crate::parsers::{LabelError, NumericError, Span, TokenSet};
+use std::borrow::Cow;
 
 /// The top-level error type for an _orignal parser_.
+///
+/// Its [`Display`] implementation includes a [`Snippet`] of the input, which points at the
+/// [`span`] at which parsing failed.
+///
+/// The error borrows the input which could not be parsed, so a failed parse does not allocate.
+/// Use [`into_owned`] to detach the error from the input, e.g. to return it as a `'static` error.
+///
+/// [`Display`]: std::fmt::Display
+/// [`span`]: OriginalParserError::span
+/// [`into_owned`]: OriginalParserError::into_owned
 #[derive(Clone, Debug, thiserror::Error)]
-#[error(
-    "Unable to parse '{input}' to a version number: {reason}{}",
-    self.fmt()
-)]
-pub struct OriginalParserError {
-    input: String,
-    cursor: Option<usize>,
+#[error("Unable to parse version number: {reason}\n{}", self.render())]
+pub struct OriginalParserError<'input> {
+    input: Cow<'input, [u8]>,
+    span: Span,
+    expected: TokenSet,
     reason: ErrorReason,
 }
 
-impl OriginalParserError {
+impl<'input> OriginalParserError<'input> {
     /// The reason why the given input could not be parsed to a [`Version`].
     pub fn reason(&self) -> &ErrorReason {
         &self.reason
     }
-}
 
-impl OriginalParserError {
-    pub(crate) fn from_parser(parser: &Parser<'_>, reason: ErrorReason) -> Self {
-        Self {
-            input: String::from_utf8_lossy(parser.slice).to_string(),
-            cursor: None,
-            reason,
+    /// The input which could not be parsed.
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    /// The span of the input at which parsing failed.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The remainder of the input, starting at the first token which was not parsed, if the
+    /// end-of-input was expected instead.
+    pub fn extra_input(&self) -> Option<&[u8]> {
+        match self.reason {
+            ErrorReason::ExpectedEndOfInput { .. } => self.input.get(self.span.start..),
+            _ => None,
         }
     }
 
-    pub(crate) fn from_parser_with_cursor(
-        slice: &Parser<'_>,
-        cursor: usize,
-        reason: ErrorReason,
-    ) -> Self {
-        Self {
-            input: String::from_utf8_lossy(slice.slice).to_string(),
-            cursor: Some(cursor),
-            reason,
+    /// The set of tokens which would have been accepted at the [`span`], if a different token was
+    /// expected.
+    ///
+    /// [`span`]: OriginalParserError::span
+    pub fn expected(&self) -> TokenSet {
+        self.expected
+    }
+
+    /// The offending character, decoded from the input, if a different token was expected.
+    ///
+    /// Returns `None` if the end-of-input was reached instead, or if the error is not caused by
+    /// an unexpected token.
+    pub fn got(&self) -> Option<char> {
+        match self.reason {
+            ErrorReason::ExpectedEndOfInput { .. }
+            | ErrorReason::ExpectedSeparator { got: Some(_) }
+            | ErrorReason::ExpectedNumericToken { got: Some(_) } => {
+                render::char_at(&self.input, self.span.start).map(|(c, _)| c)
+            }
+            _ => None,
         }
     }
 
-    fn fmt(&self) -> String {
-        if let Some(c) = self.cursor {
-            Self::squiggle(&self.input, c).unwrap_or_default()
-        } else {
-            String::default()
+    /// Render a snippet of the input, which underlines the [`span`] at which parsing failed.
+    ///
+    /// [`span`]: OriginalParserError::span
+    pub fn render(&self) -> Snippet<'_> {
+        Snippet::new(self.input.as_ref(), self.span).with_expected(self.expected)
+    }
+
+    /// Detach the error from the input, by copying the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::parsers::original::{OriginalParserError, Parser};
+    ///
+    /// fn parse(input: String) -> Result<version_number::Version, OriginalParserError<'static>> {
+    ///     Parser::from_slice(input.as_bytes())
+    ///         .parse()
+    ///         .map_err(OriginalParserError::into_owned)
+    /// }
+    ///
+    /// let err = parse("1.x".to_string()).unwrap_err();
+    /// assert_eq!(err.input(), b"1.x");
+    /// ```
+    pub fn into_owned(self) -> OriginalParserError<'static> {
+        OriginalParserError {
+            input: Cow::Owned(self.input.into_owned()),
+            span: self.span,
+            expected: self.expected,
+            reason: self.reason,
         }
     }
+}
+
+impl<'input> OriginalParserError<'input> {
+    // The input as a `Cow`, which implements `miette::SourceCode`, unlike a `[u8]`.
+    #[cfg(feature = "miette")]
+    pub(crate) fn source(&self) -> &Cow<'input, [u8]> {
+        &self.input
+    }
 
-    fn squiggle(input: &str, cursor: usize) -> Option<String> {
-        let lead = "Unable to parse '".len();
-        let err_from = lead + cursor;
-        let err_end = input.len().checked_sub(cursor + 1)?; // this may fail to look as expected if the input contains multiple lines
-
-        let spaces = std::iter::repeat_with(|| " ").take(err_from);
-        let marker = std::iter::once_with(|| "^");
-        let squiggle = std::iter::repeat_with(|| "~").take(err_end);
-        let newline = std::iter::once_with(|| "\n");
-
-        Some(
-            newline
-                .clone()
-                .chain(spaces)
-                .chain(marker)
-                .chain(squiggle)
-                .chain(newline)
-                .collect(),
-        )
+    pub(crate) fn from_parser(parser: &Parser<'input>, span: Span, reason: ErrorReason) -> Self {
+        Self::from_parser_expecting(parser, span, TokenSet::EMPTY, reason)
+    }
+
+    pub(crate) fn from_parser_expecting(
+        parser: &Parser<'input>,
+        span: Span,
+        expected: TokenSet,
+        reason: ErrorReason,
+    ) -> Self {
+        Self {
+            input: Cow::Borrowed(parser.slice),
+            span,
+            expected,
+            reason,
+        }
     }
 }
 
@@ -77,16 +136,17 @@ impl OriginalParserError {
 pub enum ErrorReason {
     /// When this error variant is returned, the parser expected that no more
     /// tokens should be present, but instead 1 or more additional tokens
-    /// were not parsed yet. The `extra_input` field contains the remaining
-    /// tokens.
+    /// were not parsed yet.
     ///
-    /// The error display implementation tries to print these remaining tokens
-    /// as a [`String`].
-    #[error("Expected end of input after parsing third version number component, but got: '{}'", String::from_utf8_lossy(.extra_input.as_slice()))]
+    /// The remaining tokens are available from [`OriginalParserError::extra_input`].
+    #[error(
+        "Expected end of input after parsing third version number component, but got '{}'",
+        Byte(Some(*.got)),
+    )]
     ExpectedEndOfInput {
-        /// A `Vec` of unexpected tokens, which were still present while the parser
-        /// expected to have reached the end-of-input for the given input.
-        extra_input: Vec<u8>,
+        /// The first token which was still present while the parser expected to have reached
+        /// the end-of-input.
+        got: u8,
     },
 
     /// When this error variant is returned, the '.' token was expected, but
@@ -95,7 +155,7 @@ pub enum ErrorReason {
     /// The `got` field shows the token read.
     #[error(
         "Expected the dot-separator '.', but got '{}'",
-        .got.map(|c| String::from(char::from(c))).unwrap_or_else(|| "EOI".to_string()),
+        Byte(*.got),
     )]
     ExpectedSeparator {
         /// Token read, or `None` if we unexpectedly got the end-of-input.
@@ -106,7 +166,7 @@ pub enum ErrorReason {
     /// a different token was present, or the end-of-input reached.
     #[error(
         "Expected 0-9, but got '{}'",
-        .got.map(|c| String::from(char::from(c))).unwrap_or_else(|| "EOI".to_string()),
+        Byte(*.got),
     )]
     ExpectedNumericToken {
         /// Token read, or `None` if we unexpectedly got the end-of-input.
@@ -116,6 +176,12 @@ pub enum ErrorReason {
     /// An error variant for faults when parsing and constructing a number.
     #[error(transparent)]
     NumberError(#[from] NumberError),
+
+    /// An error variant for faults when parsing the labels of a [`LabeledVersion`].
+    ///
+    /// [`LabeledVersion`]: crate::LabeledVersion
+    #[error(transparent)]
+    LabelError(#[from] LabelError),
 }
 
 /// An error type for faults relating to parsing and constructing numbers.
@@ -133,31 +199,38 @@ pub enum NumberError {
     Overflow,
 }
 
-impl From<OriginalParserError> for ParserError {
-    fn from(value: OriginalParserError) -> Self {
-        match value.reason {
+impl From<OriginalParserError<'_>> for ParserError {
+    fn from(value: OriginalParserError<'_>) -> Self {
+        let OriginalParserError {
+            input,
+            span,
+            expected,
+            reason,
+        } = value;
+
+        match reason {
             ErrorReason::NumberError(e) => match e {
-                NumberError::LeadingZero => ParserError::Numeric(NumericError::LeadingZero),
-                NumberError::Overflow => ParserError::Numeric(NumericError::Overflow),
+                NumberError::LeadingZero => ParserError::new(span, NumericError::LeadingZero),
+                NumberError::Overflow => ParserError::new(span, NumericError::Overflow),
             },
-            ErrorReason::ExpectedEndOfInput { extra_input } => {
-                ParserError::Expected(ExpectedError::EndOfInput {
-                    at: value.cursor,
-                    got: char::from(extra_input[0]),
-                })
-            }
-            ErrorReason::ExpectedSeparator { got } => {
-                ParserError::Expected(ExpectedError::Separator {
-                    at: value.cursor,
-                    got: got.map(char::from),
-                })
-            }
-            ErrorReason::ExpectedNumericToken { got } => {
-                ParserError::Expected(ExpectedError::Numeric {
-                    at: value.cursor,
-                    got: got.map(char::from),
-                })
+            ErrorReason::ExpectedEndOfInput { got } => ParserError::new(
+                span,
+                ExpectedError {
+                    expected,
+                    got: ExpectedError::got_byte(Some(got)),
+                },
+            ),
+            ErrorReason::ExpectedSeparator { got } | ErrorReason::ExpectedNumericToken { got } => {
+                ParserError::new(
+                    span,
+                    ExpectedError {
+                        expected,
+                        got: ExpectedError::got_byte(got),
+                    },
+                )
             }
+            ErrorReason::LabelError(e) => ParserError::new(span, e),
         }
+        .decode_got(&input)
     }
 }
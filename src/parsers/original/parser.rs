@@ -1,4 +1,8 @@
+use crate::parsers::error::expected;
+use crate::parsers::labels;
+use crate::parsers::modular::component::starts_with_patch;
 use crate::parsers::original::{ErrorReason, NumberError, OriginalParserError};
+use crate::parsers::{Prefix, Span, TokenSet};
 
 macro_rules! to_number {
     ($initial:expr) => {
@@ -103,11 +107,11 @@ impl<'slice> Parser<'slice> {
     /// let version = parser.parse().unwrap();
     ///
     /// assert_eq!(version, Version::new_base_version(1, 2));
-    pub fn parse(&self) -> Result<crate::Version, OriginalParserError> {
+    pub fn parse(&self) -> Result<crate::Version, OriginalParserError<'slice>> {
         let mut cursor = 0;
 
         let first = self.parse_number(&mut cursor)?;
-        self.parse_dot(&mut cursor)?;
+        self.parse_dot(&mut cursor, expected::SEPARATOR)?;
         let second = self.parse_number(&mut cursor)?;
 
         if self.is_done(cursor) {
@@ -119,7 +123,7 @@ impl<'slice> Parser<'slice> {
         }
 
         // is_done = false
-        self.parse_dot(&mut cursor)?;
+        self.parse_dot(&mut cursor, expected::SEPARATOR_OR_END)?;
         let third = self.parse_number(&mut cursor)?;
 
         if self.is_done(cursor) {
@@ -131,16 +135,157 @@ impl<'slice> Parser<'slice> {
             }));
         }
 
-        Err(OriginalParserError::from_parser_with_cursor(
-            self,
+        Err(self.expected_end_of_input(cursor, expected::END))
+    }
+
+    /// Parse a two- or three component version number, optionally followed by a pre-release
+    /// label and/or build metadata, from the given input.
+    ///
+    /// Unlike [`Parser::parse`], this method accepts labels such as `-beta.3` and `+build.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::parsers::original::Parser;
+    /// use version_number::Version;
+    ///
+    /// let parser = Parser::from_slice("1.70.0-beta.3".as_bytes());
+    /// let labeled = parser.parse_labeled().unwrap();
+    ///
+    /// assert_eq!(labeled.version, Version::new_full_version(1, 70, 0));
+    /// assert_eq!(labeled.pre.as_str(), "beta.3");
+    /// ```
+    pub fn parse_labeled(&self) -> Result<crate::LabeledVersion, OriginalParserError<'slice>> {
+        let mut cursor = 0;
+
+        let first = self.parse_number(&mut cursor)?;
+        self.parse_dot(&mut cursor, expected::SEPARATOR)?;
+        let second = self.parse_number(&mut cursor)?;
+
+        let version = if self.is_done(cursor) || self.peek_is_label(cursor) {
+            crate::Version::Base(crate::BaseVersion {
+                major: first.as_value(),
+                minor: second.as_value(),
+            })
+        } else {
+            self.parse_dot(&mut cursor, expected::SEPARATOR_LABEL_OR_END)?;
+            let third = self.parse_number(&mut cursor)?;
+
+            crate::Version::Full(crate::FullVersion {
+                major: first.as_value(),
+                minor: second.as_value(),
+                patch: third.as_value(),
+            })
+        };
+
+        // The tokens which may follow the version, if it is not followed by a label.
+        let expected = match version {
+            crate::Version::Base(_) => expected::SEPARATOR_LABEL_OR_END,
+            crate::Version::Full(_) => expected::LABEL_OR_END,
+        };
+
+        let mut iter = self.slice[cursor..].iter().peekable();
+        let labels = labels::parse_labels(&mut iter, self.slice.len());
+        let cursor = self.slice.len() - iter.len();
+
+        let (pre, build) = labels
+            .map_err(|(span, error)| OriginalParserError::from_parser(self, span, error.into()))?;
+
+        if self.is_done(cursor) {
+            return Ok(crate::LabeledVersion::new(version, pre, build));
+        }
+
+        Err(self.expected_end_of_input(cursor, expected))
+    }
+
+    /// Parse a two- or three component version number from the start of the given input, and
+    /// return it together with the remainder of the input.
+    ///
+    /// Parsing is greedy: the patch component is parsed if the minor component is followed by a
+    /// separator and a digit. Unlike [`Parser::parse`], remaining input is not rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::parsers::original::Parser;
+    /// use version_number::Version;
+    ///
+    /// let parser = Parser::from_slice("1.2.3.tar.gz".as_bytes());
+    /// let prefix = parser.parse_prefix().unwrap();
+    ///
+    /// assert_eq!(prefix.value, Version::new_full_version(1, 2, 3));
+    /// assert_eq!(prefix.remainder, b".tar.gz");
+    /// assert_eq!(prefix.consumed, 5);
+    /// ```
+    pub fn parse_prefix(
+        &self,
+    ) -> Result<Prefix<'slice, crate::Version>, OriginalParserError<'slice>> {
+        let base = self.parse_base_prefix()?;
+
+        if !starts_with_patch(&self.slice[base.consumed..]) {
+            return Ok(base.map(crate::Version::Base));
+        }
+
+        let mut cursor = base.consumed + 1;
+        let patch = self.parse_number(&mut cursor)?;
+
+        Ok(Prefix::new(
+            crate::Version::Full(crate::FullVersion {
+                major: base.value.major,
+                minor: base.value.minor,
+                patch: patch.as_value(),
+            }),
+            self.slice,
             cursor,
-            ErrorReason::ExpectedEndOfInput {
-                extra_input: self.slice[cursor..].to_vec(),
+        ))
+    }
+
+    // Parse the major and minor components from the start of the input.
+    pub(crate) fn parse_base_prefix(
+        &self,
+    ) -> Result<Prefix<'slice, crate::BaseVersion>, OriginalParserError<'slice>> {
+        let mut cursor = 0;
+
+        let first = self.parse_number(&mut cursor)?;
+        self.parse_dot(&mut cursor, expected::SEPARATOR)?;
+        let second = self.parse_number(&mut cursor)?;
+
+        Ok(Prefix::new(
+            crate::BaseVersion {
+                major: first.as_value(),
+                minor: second.as_value(),
             },
+            self.slice,
+            cursor,
         ))
     }
 
-    fn parse_number(&self, cursor: &mut usize) -> Result<NumberConstructor, OriginalParserError> {
+    // Parse the major, minor and patch components from the start of the input.
+    pub(crate) fn parse_full_prefix(
+        &self,
+    ) -> Result<Prefix<'slice, crate::FullVersion>, OriginalParserError<'slice>> {
+        let base = self.parse_base_prefix()?;
+
+        let mut cursor = base.consumed;
+        self.parse_dot(&mut cursor, expected::SEPARATOR)?;
+        let third = self.parse_number(&mut cursor)?;
+
+        Ok(Prefix::new(
+            crate::FullVersion {
+                major: base.value.major,
+                minor: base.value.minor,
+                patch: third.as_value(),
+            },
+            self.slice,
+            cursor,
+        ))
+    }
+
+    fn parse_number(
+        &self,
+        cursor: &mut usize,
+    ) -> Result<NumberConstructor, OriginalParserError<'slice>> {
+        let start = *cursor;
         let mut value = NumberComponent::new();
 
         while let Some(&b) = self.slice.get(*cursor) {
@@ -148,41 +293,74 @@ impl<'slice> Parser<'slice> {
                 break;
             }
 
-            value
-                .insert_digit(b)
-                .map_err(|error| OriginalParserError::from_parser(self, error.into()))?;
+            value.insert_digit(b).map_err(|error| {
+                OriginalParserError::from_parser(self, self.number_span(start), error.into())
+            })?;
 
             *cursor += 1;
         }
 
         value.get().ok_or_else(|| {
-            OriginalParserError::from_parser_with_cursor(
+            let got = self.slice.get(*cursor).copied();
+
+            OriginalParserError::from_parser_expecting(
                 self,
-                *cursor,
-                ErrorReason::ExpectedNumericToken { got: None },
+                Span::token(*cursor, got),
+                expected::NUMBER,
+                ErrorReason::ExpectedNumericToken { got },
             )
         })
     }
 
-    fn parse_dot(&self, cursor: &mut usize) -> Result<(), OriginalParserError> {
+    fn parse_dot(
+        &self,
+        cursor: &mut usize,
+        expected: TokenSet,
+    ) -> Result<(), OriginalParserError<'slice>> {
         match self.slice.get(*cursor) {
             Some(&b'.') => {
                 *cursor += 1;
                 Ok(())
             }
-            Some(&b) => Err(OriginalParserError::from_parser_with_cursor(
-                self,
-                *cursor,
-                ErrorReason::ExpectedSeparator { got: Some(b) },
-            )),
-            None => Err(OriginalParserError::from_parser_with_cursor(
+            got => Err(OriginalParserError::from_parser_expecting(
                 self,
-                *cursor,
-                ErrorReason::ExpectedSeparator { got: None },
+                Span::token(*cursor, got),
+                expected,
+                ErrorReason::ExpectedSeparator { got: got.copied() },
             )),
         }
     }
 
+    fn expected_end_of_input(
+        &self,
+        cursor: usize,
+        expected: TokenSet,
+    ) -> OriginalParserError<'slice> {
+        OriginalParserError::from_parser_expecting(
+            self,
+            Span::new(cursor, cursor + 1),
+            expected,
+            ErrorReason::ExpectedEndOfInput {
+                got: self.slice[cursor],
+            },
+        )
+    }
+
+    // The span of the number component which starts at `start`, up to the first token which is
+    // not a digit.
+    fn number_span(&self, start: usize) -> Span {
+        let len = self.slice[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+
+        Span::new(start, start + len)
+    }
+
+    fn peek_is_label(&self, cursor: usize) -> bool {
+        matches!(self.slice.get(cursor), Some(b'-' | b'+'))
+    }
+
     fn is_done(&self, cursor: usize) -> bool {
         cursor >= self.slice.len()
     }
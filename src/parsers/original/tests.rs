@@ -1,4 +1,6 @@
+use crate::parsers::error::expected;
 use crate::parsers::original::{ErrorReason, NumberError, Parser};
+use crate::parsers::{LabelError, ParserError, Span, TokenSet};
 use crate::Version;
 
 #[test]
@@ -99,3 +101,134 @@ fn failure_variations(input: &str) {
 
     assert!(result.is_err());
 }
+
+#[yare::parameterized(
+    base = { "1.2", Version::new_base_version(1, 2), "", "" },
+    base_build = { "1.2+7", Version::new_base_version(1, 2), "", "7" },
+    full_pre = { "1.70.0-beta.3", Version::new_full_version(1, 70, 0), "beta.3", "" },
+    full_pre_build = { "1.2.3-x.7.z.92+exp.sha.5114f85", Version::new_full_version(1, 2, 3), "x.7.z.92", "exp.sha.5114f85" },
+)]
+fn labeled(input: &str, version: Version, pre: &str, build: &str) {
+    let p = Parser::from_slice(input.as_bytes());
+    let labeled = p.parse_labeled().unwrap();
+
+    assert_eq!(labeled.version, version);
+    assert_eq!(labeled.pre.as_str(), pre);
+    assert_eq!(labeled.build.as_str(), build);
+}
+
+#[yare::parameterized(
+    empty_build = { "1.2.3+", ErrorReason::LabelError(LabelError::EmptyIdentifier) },
+    leading_zero = { "1.2.3-beta.01", ErrorReason::LabelError(LabelError::LeadingZero) },
+    invalid = { "1.2.3-beta 1", ErrorReason::LabelError(LabelError::InvalidCharacter { got: b' ' }) },
+    not_a_label = { "1.2.3 ", ErrorReason::ExpectedEndOfInput { got: b' ' } },
+)]
+fn labeled_rejected(input: &str, expected: ErrorReason) {
+    let p = Parser::from_slice(input.as_bytes());
+    let result = p.parse_labeled();
+
+    assert_eq!(result.unwrap_err().reason(), &expected);
+}
+
+#[yare::parameterized(
+    leading_zero = { "1.02", Span::new(2, 4), TokenSet::EMPTY },
+    no_number = { "1.", Span::empty(2), expected::NUMBER },
+    not_a_separator = { "1x", Span::new(1, 2), expected::SEPARATOR },
+    extra_input = { "1.2.3.", Span::new(5, 6), expected::LABEL_OR_END },
+    extra_input_base = { "1.2~", Span::new(3, 4), expected::SEPARATOR_LABEL_OR_END },
+    label = { "1.2.3-01", Span::new(6, 8), TokenSet::EMPTY },
+)]
+fn error_span(input: &str, span: Span, expected: TokenSet) {
+    let p = Parser::from_slice(input.as_bytes());
+    let err = p.parse_labeled().unwrap_err();
+
+    assert_eq!(err.span(), span);
+    assert_eq!(err.expected(), expected);
+
+    let err = ParserError::from(err);
+
+    assert_eq!(err.span(), span);
+    assert_eq!(err.expected(), expected);
+}
+
+#[test]
+fn display_renders_snippet() {
+    let p = Parser::from_slice("1.2\n3".as_bytes());
+    let err = p.parse().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Unable to parse version number: Expected the dot-separator '.', but got '\\x0A'\n \
+         --> line 1, column 4\n  |\n1 | 1.2\n  |    ^ expected 0-9, '.' or EOI\n"
+    );
+}
+
+#[test]
+fn got_is_decoded() {
+    let p = Parser::from_slice("1.2é".as_bytes());
+    let err = p.parse().unwrap_err();
+
+    assert_eq!(err.got(), Some('é'));
+    assert_eq!(err.render().location().column, 4);
+
+    let err = ParserError::from(err);
+
+    assert_eq!(err.got(), Some('é'));
+    assert_eq!(err.span(), Span::new(3, 5));
+}
+
+#[yare::parameterized(
+    full = { "1.2.3.4", Some(&b".4"[..]) },
+    whitespace = { "1.2.3 ", Some(&b" "[..]) },
+    not_at_end = { "1.x", None },
+)]
+fn extra_input(input: &str, expected: Option<&[u8]>) {
+    let p = Parser::from_slice(input.as_bytes());
+    let err = p.parse().unwrap_err();
+
+    assert_eq!(err.extra_input(), expected);
+}
+
+#[test]
+fn into_owned() {
+    let input = String::from("1.02");
+    let err = Parser::from_slice(input.as_bytes())
+        .parse()
+        .unwrap_err()
+        .into_owned();
+    drop(input);
+
+    assert_eq!(err.input(), b"1.02");
+    assert_eq!(err.span(), Span::new(2, 4));
+    assert_eq!(
+        err.reason(),
+        &ErrorReason::NumberError(NumberError::LeadingZero)
+    );
+}
+
+#[yare::parameterized(
+    base = { "1.2", Version::new_base_version(1, 2), "" },
+    archive = { "1.2.3.tar.gz", Version::new_full_version(1, 2, 3), ".tar.gz" },
+    rustc = { "1.70.0 (90c541806 2023-05-31)", Version::new_full_version(1, 70, 0), " (90c541806 2023-05-31)" },
+    dot_not_followed_by_digit = { "1.2.x", Version::new_base_version(1, 2), ".x" },
+    label = { "1.2.3-beta", Version::new_full_version(1, 2, 3), "-beta" },
+)]
+fn parse_prefix(input: &str, version: Version, remainder: &str) {
+    let p = Parser::from_slice(input.as_bytes());
+    let prefix = p.parse_prefix().unwrap();
+
+    assert_eq!(prefix.value, version);
+    assert_eq!(prefix.remainder, remainder.as_bytes());
+    assert_eq!(prefix.consumed, input.len() - remainder.len());
+}
+
+#[yare::parameterized(
+    no_minor = { "1-beta", Span::new(1, 2) },
+    leading_zero = { "1.2.03", Span::new(4, 6) },
+)]
+fn parse_prefix_rejected(input: &str, span: Span) {
+    let p = Parser::from_slice(input.as_bytes());
+    let err = p.parse_prefix().unwrap_err();
+
+    assert_eq!(err.span(), span);
+}
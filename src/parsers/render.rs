@@ -0,0 +1,373 @@
+//! Rendering of parser errors, as a snippet of the input which points at the offending span.
+//!
+//! A [`Snippet`] computes the line and column of the span, and underlines the span in the line of
+//! the input in which it starts. Lines and columns are counted from `1`, where each column is a
+//! character, and not a byte, so multi-line and non-ASCII input are rendered as expected. Byte
+//! sequences which are not valid UTF-8 are rendered as the `U+FFFD` replacement character.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::parsers::render::Location;
+//! use version_number::BaseVersion;
+//!
+//! let input = "1.2x";
+//! let err = BaseVersion::parse(input).unwrap_err();
+//! let snippet = err.render(input).with_source_name("Cargo.toml:7");
+//!
+//! assert_eq!(snippet.location(), Location { line: 1, column: 4 });
+//! assert_eq!(
+//!     snippet.to_string(),
+//!     " --> Cargo.toml:7, line 1, column 4\n  |\n1 | 1.2x\n  |    ^ expected 0-9 or EOI\n",
+//! );
+//! ```
+
+#[cfg(feature = "std")]
+use crate::parsers::{Span, TokenSet};
+use core::fmt;
+
+/// The line and column of a byte offset in the input, both counted from `1`.
+///
+/// The column counts characters, not bytes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Location {
+    /// The line, counted from `1`.
+    pub line: usize,
+    /// The column in characters, counted from `1`.
+    pub column: usize,
+}
+
+impl Location {
+    /// Compute the location of the given byte `offset` of the `input`.
+    ///
+    /// An offset which lies within a multi-byte character is located at that character. An offset
+    /// beyond the end of the input is located just after the last character.
+    pub fn of(input: &[u8], offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = line_start(input, offset);
+
+        Self {
+            line: 1 + input[..line_start].iter().filter(|&&b| b == b'\n').count(),
+            column: 1 + chars(&input[line_start..])
+                .take_while(|&(at, _, len)| line_start + at + len <= offset)
+                .count(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A snippet of the input, which underlines the span at which parsing failed.
+///
+/// The snippet is rendered by its [`Display`] implementation, which writes the location of the
+/// span, optionally preceded by the name of the source of the input, and the line of the input
+/// in which the span starts, with the span underlined.
+///
+/// Requires the `std` feature.
+///
+/// [`Display`]: fmt::Display
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct Snippet<'a> {
+    input: &'a [u8],
+    span: Span,
+    label: Option<String>,
+    source_name: Option<&'a str>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> Snippet<'a> {
+    /// Create a new snippet of the `input`, which underlines the given `span`.
+    pub fn new<B: AsRef<[u8]> + ?Sized>(input: &'a B, span: Span) -> Self {
+        Self {
+            input: input.as_ref(),
+            span,
+            label: None,
+            source_name: None,
+        }
+    }
+
+    /// Name the source of the input, e.g. `Cargo.toml:7`, which is written before the location of
+    /// the span.
+    pub fn with_source_name(mut self, name: &'a str) -> Self {
+        self.source_name = Some(name);
+        self
+    }
+
+    /// Label the underlined span, e.g. with the tokens which were expected.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Label the underlined span with the `expected` tokens, unless the set is empty.
+    pub(crate) fn with_expected(self, expected: TokenSet) -> Self {
+        if expected.is_empty() {
+            self
+        } else {
+            self.with_label(format!("expected {}", expected))
+        }
+    }
+
+    /// The location at which the span starts.
+    pub fn location(&self) -> Location {
+        Location::of(self.input, self.span.start)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Snippet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = self.location();
+        let start = self.span.start.min(self.input.len());
+        let line_start = line_start(self.input, start);
+        let line_end = self.input[line_start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(self.input.len(), |end| line_start + end);
+        let line = &self.input[line_start..line_end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        let gutter = location.line.to_string().len();
+
+        match self.source_name {
+            Some(name) => writeln!(f, "{:w$}--> {}, {}", "", name, location, w = gutter)?,
+            None => writeln!(f, "{:w$}--> {}", "", location, w = gutter)?,
+        }
+        writeln!(f, "{:w$} |", "", w = gutter)?;
+
+        write!(f, "{} | ", location.line)?;
+        for (_, c, _) in chars(line) {
+            write!(f, "{}", c)?;
+        }
+        writeln!(f)?;
+
+        write!(f, "{:w$} | ", "", w = gutter)?;
+        let mut underlined = 0;
+        for (at, c, _) in chars(line) {
+            let at = line_start + at;
+
+            if at < start {
+                // Keep tabs, so the underline is aligned with the input, however wide a tab is.
+                f.write_str(if c == '\t' { "\t" } else { " " })?;
+            } else if at < self.span.end {
+                underlined += 1;
+            }
+        }
+        for _ in 0..underlined.max(1) {
+            f.write_str("^")?;
+        }
+
+        match &self.label {
+            Some(label) => writeln!(f, " {}", label),
+            None => writeln!(f),
+        }
+    }
+}
+
+/// Decode the character which starts at the byte `offset` of the `input`, and return it together
+/// with its length in bytes.
+///
+/// Returns `None` if the offset is at or beyond the end of the input. A byte which does not start
+/// a valid UTF-8 sequence is decoded as the `U+FFFD` replacement character, with a length of `1`.
+pub fn char_at(input: &[u8], offset: usize) -> Option<(char, usize)> {
+    let rest = input.get(offset..)?;
+    let first = *rest.first()?;
+
+    let len = match first {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => 0,
+    };
+
+    let decoded = rest
+        .get(..len)
+        .and_then(|bytes| core::str::from_utf8(bytes).ok())
+        .and_then(|s| s.chars().next());
+
+    Some(decoded.map_or((char::REPLACEMENT_CHARACTER, 1), |c| (c, len)))
+}
+
+/// Displays a single byte token of the input, or `EOI` for the end-of-input.
+///
+/// Printable ASCII is written as is, while any other byte is escaped as `\xNN`, since a single byte
+/// of a multi-byte character can't be displayed as a character.
+pub(crate) struct Byte(pub(crate) Option<u8>);
+
+impl fmt::Display for Byte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(b) if b == b' ' || b.is_ascii_graphic() => write!(f, "{}", char::from(b)),
+            Some(b) => write!(f, "\\x{:02X}", b),
+            None => f.write_str("EOI"),
+        }
+    }
+}
+
+/// Displays a decoded character token of the input, or `EOI` for the end-of-input.
+///
+/// ASCII is written like a [`Byte`], while any other character is written as is, unless it is not
+/// printable, in which case it is escaped as `\u{NNNN}`.
+pub(crate) struct Char(pub(crate) Option<char>);
+
+impl fmt::Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(c) if c.is_ascii() => Byte(Some(c as u8)).fmt(f),
+            Some(c) => write!(f, "{}", c.escape_debug()),
+            None => f.write_str("EOI"),
+        }
+    }
+}
+
+// The characters of the input, with their byte offset and length.
+fn chars(input: &[u8]) -> impl Iterator<Item = (usize, char, usize)> + '_ {
+    let mut at = 0;
+
+    core::iter::from_fn(move || {
+        let (c, len) = char_at(input, at)?;
+        let item = (at, c, len);
+        at += len;
+        Some(item)
+    })
+}
+
+// The byte offset at which the line which contains the byte `offset` starts.
+fn line_start(input: &[u8], offset: usize) -> usize {
+    input[..offset]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |newline| newline + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[yare::parameterized(
+        start = { "1.2x", 0, 1, 1 },
+        ascii = { "1.2x", 3, 1, 4 },
+        end = { "1.2", 3, 1, 4 },
+        beyond_end = { "1.2", 10, 1, 4 },
+        second_line = { "1.2\n3.x", 6, 2, 3 },
+        crlf = { "1.2\r\n3.x", 7, 2, 3 },
+        after_multibyte = { "é1.x", 4, 1, 4 },
+        within_multibyte = { "1.é", 3, 1, 3 },
+    )]
+    fn location(input: &str, offset: usize, line: usize, column: usize) {
+        assert_eq!(
+            Location::of(input.as_bytes(), offset),
+            Location { line, column }
+        );
+    }
+
+    #[yare::parameterized(
+        ascii = { b"1.x", 2, Some(('x', 1)) },
+        two_bytes = { "1.é".as_bytes(), 2, Some(('é', 2)) },
+        four_bytes = { "1.🦀".as_bytes(), 2, Some(('🦀', 4)) },
+        continuation_byte = { "1.é".as_bytes(), 3, Some((char::REPLACEMENT_CHARACTER, 1)) },
+        invalid = { b"1.\xff", 2, Some((char::REPLACEMENT_CHARACTER, 1)) },
+        truncated = { b"1.\xc3", 2, Some((char::REPLACEMENT_CHARACTER, 1)) },
+        end = { b"1.2", 3, None },
+    )]
+    fn decode(input: &[u8], offset: usize, expected: Option<(char, usize)>) {
+        assert_eq!(char_at(input, offset), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render() {
+        let snippet = Snippet::new("1.2x", Span::new(3, 4));
+
+        assert_eq!(
+            snippet.to_string(),
+            " --> line 1, column 4\n  |\n1 | 1.2x\n  |    ^\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render_span() {
+        let snippet = Snippet::new("1.02", Span::new(2, 4)).with_label("leading zero");
+
+        assert_eq!(
+            snippet.to_string(),
+            " --> line 1, column 3\n  |\n1 | 1.02\n  |   ^^ leading zero\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render_end_of_input() {
+        let snippet = Snippet::new("1.", Span::empty(2));
+
+        assert_eq!(
+            snippet.to_string(),
+            " --> line 1, column 3\n  |\n1 | 1.\n  |   ^\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render_multi_line() {
+        let input = "1.2\n\t3.4\nfoo\n\n\n\n\n\n\n";
+        let snippet = Snippet::new(input, Span::new(7, 9)).with_source_name("versions.txt");
+
+        assert_eq!(
+            snippet.to_string(),
+            " --> versions.txt, line 2, column 4\n  |\n2 | \t3.4\n  | \t  ^\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render_wide_gutter() {
+        let input = "\n".repeat(9) + "1.x";
+        let snippet = Snippet::new(&input, Span::new(11, 12));
+
+        assert_eq!(
+            snippet.to_string(),
+            "  --> line 10, column 3\n   |\n10 | 1.x\n   |   ^\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render_multibyte() {
+        let input = "é.ü";
+        let snippet = Snippet::new(input, Span::new(3, 4));
+
+        assert_eq!(
+            snippet.to_string(),
+            " --> line 1, column 3\n  |\n1 | é.ü\n  |   ^\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn render_invalid_utf8() {
+        let snippet = Snippet::new(b"1.\xff", Span::new(2, 3));
+
+        assert_eq!(
+            snippet.to_string(),
+            " --> line 1, column 3\n  |\n1 | 1.\u{fffd}\n  |   ^\n"
+        );
+    }
+
+    #[yare::parameterized(
+        ascii = { Some(b'x'), "x" },
+        space = { Some(b' '), " " },
+        newline = { Some(b'\n'), "\\x0A" },
+        non_ascii = { Some(0xc3), "\\xC3" },
+        eoi = { None, "EOI" },
+    )]
+    fn display_byte(byte: Option<u8>, expected: &str) {
+        assert_eq!(Byte(byte).to_string(), expected);
+    }
+}
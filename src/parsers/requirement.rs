@@ -0,0 +1,30 @@
+//! The _requirement parser_ module.
+//!
+//! Unlike the other parsers, which parse a [`Version`], this parser parses a [`VersionReq`],
+//! such as `>=1.56, <1.70`. The components of each comparator are parsed with the same rules
+//! as the components of a version number, i.e. leading zeros are rejected, and each component
+//! must fit in a 64 bits unsigned integer.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::parsers::requirement::Parser;
+//! use version_number::{Comparator, Op};
+//!
+//! let parser = Parser::from_slice(">=1.56, <1.70".as_bytes());
+//! let requirement = parser.parse().unwrap();
+//!
+//! assert_eq!(requirement.comparators[0], Comparator { op: Op::GreaterEq, major: 1, minor: Some(56), patch: None });
+//! assert_eq!(requirement.comparators[1], Comparator { op: Op::Less, major: 1, minor: Some(70), patch: None });
+//! ```
+//!
+//! [`Version`]: crate::Version
+//! [`VersionReq`]: crate::VersionReq
+
+pub use error::RequirementParserError;
+#[cfg(feature = "std")]
+pub use parser::Parser;
+
+mod error;
+#[cfg(feature = "std")]
+mod parser;
@@ -0,0 +1,87 @@
+use crate::parsers::render::Byte;
+use crate::parsers::NumericError;
+use core::fmt;
+
+/// Errors which may be returned during parsing, by the _requirement parser_.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequirementParserError {
+    /// When this error variant is returned, the input did not contain a single comparator,
+    /// or wildcard.
+    Empty,
+
+    /// When this error variant is returned, a numeric token was expected, but
+    /// a different token was present, or the end-of-input reached.
+    ExpectedNumericToken {
+        /// Place where the token was expected.
+        at: usize,
+        /// Token read, or `None` if we unexpectedly got the end-of-input.
+        got: Option<u8>,
+    },
+
+    /// When this error variant is returned, the comma-separator between two comparators was
+    /// expected, but a different token was present.
+    ExpectedComma {
+        /// Place where the comma was expected.
+        at: usize,
+        /// Token read instead.
+        got: u8,
+    },
+
+    /// When this error variant is returned, the parser expected that no more
+    /// tokens should be present, but instead 1 or more additional tokens
+    /// were not parsed yet.
+    ExpectedEndOfInput {
+        /// Place where the end-of-input was expected.
+        at: usize,
+        /// Token read instead.
+        got: u8,
+    },
+
+    /// When this error variant is returned, a wildcard was found in a place where it is not
+    /// allowed. A wildcard may not be combined with an operator, and may not be followed by a
+    /// numeric component.
+    UnexpectedWildcard {
+        /// Place of the wildcard.
+        at: usize,
+    },
+
+    /// An error variant for faults when parsing and constructing a number.
+    Numeric {
+        /// Place where the number component starts.
+        at: usize,
+        /// Place where the number component ends, i.e. the place after its last digit.
+        end: usize,
+        /// The reason why the number component could not be parsed.
+        error: NumericError,
+    },
+}
+
+impl fmt::Display for RequirementParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("Expected a version requirement, but got an empty input"),
+            Self::ExpectedNumericToken { at, got } => {
+                write!(f, "Expected 0-9 at {}, but got '{}'", at, Byte(*got))
+            }
+            Self::ExpectedComma { at, got } => write!(
+                f,
+                "Expected the comma-separator ',' at {}, but got '{}'",
+                at,
+                Byte(Some(*got))
+            ),
+            Self::ExpectedEndOfInput { at, got } => write!(
+                f,
+                "Expected end of input at {}, but got '{}'",
+                at,
+                Byte(Some(*got))
+            ),
+            Self::UnexpectedWildcard { at } => write!(f, "Unexpected wildcard at {}", at),
+            Self::Numeric { at, error, .. } => {
+                write!(f, "Unable to parse number component at {}: {}", at, error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RequirementParserError {}
@@ -0,0 +1,279 @@
+use crate::parsers::modular::component::parse_component;
+use crate::parsers::modular::{ModularParserError, NumberError};
+use crate::parsers::requirement::RequirementParserError;
+use crate::parsers::NumericError;
+use crate::{Comparator, Op, VersionReq};
+
+/// The _requirement parser_, which parses a [`VersionReq`].
+///
+/// # Example
+///
+/// ```
+/// use version_number::parsers::requirement::Parser;
+/// use version_number::{Version, VersionReq};
+///
+/// let parser = Parser::from_slice("~1.2".as_bytes());
+/// let requirement = parser.parse().unwrap();
+///
+/// assert!(requirement.matches(&Version::new_full_version(1, 2, 3)));
+/// ```
+#[derive(Debug)]
+pub struct Parser<'slice> {
+    slice: &'slice [u8],
+}
+
+impl<'slice> Parser<'slice> {
+    /// Construct a new [`Parser`] from a slice of bytes.
+    pub fn from_slice(slice: &'slice [u8]) -> Self {
+        Parser { slice }
+    }
+
+    /// Parse a version requirement, consisting of one or more comma separated comparators, or
+    /// a single `*` wildcard.
+    pub fn parse(&self) -> Result<VersionReq, RequirementParserError> {
+        let mut cursor = 0;
+        self.skip_whitespace(&mut cursor);
+
+        if self.is_done(cursor) {
+            return Err(RequirementParserError::Empty);
+        }
+
+        if self.peek_is_wildcard(cursor) {
+            cursor += 1;
+            self.parse_end(&mut cursor)?;
+
+            return Ok(VersionReq::STAR);
+        }
+
+        let mut comparators = vec![self.parse_single(&mut cursor)?];
+        self.skip_whitespace(&mut cursor);
+
+        while let Some(&token) = self.slice.get(cursor) {
+            if token != b',' {
+                return Err(RequirementParserError::ExpectedComma {
+                    at: cursor,
+                    got: token,
+                });
+            }
+
+            cursor += 1;
+            self.skip_whitespace(&mut cursor);
+            comparators.push(self.parse_single(&mut cursor)?);
+            self.skip_whitespace(&mut cursor);
+        }
+
+        Ok(VersionReq { comparators })
+    }
+
+    /// Parse a single version comparator, such as `>=1.56`.
+    pub fn parse_comparator(&self) -> Result<Comparator, RequirementParserError> {
+        let mut cursor = 0;
+        self.skip_whitespace(&mut cursor);
+
+        if self.is_done(cursor) {
+            return Err(RequirementParserError::Empty);
+        }
+
+        let comparator = self.parse_single(&mut cursor)?;
+        self.parse_end(&mut cursor)?;
+
+        Ok(comparator)
+    }
+
+    fn parse_single(&self, cursor: &mut usize) -> Result<Comparator, RequirementParserError> {
+        let op = self.parse_op(cursor);
+        self.skip_whitespace(cursor);
+
+        let major = self.parse_number(cursor)?;
+        let mut comparator = Comparator {
+            op: op.unwrap_or(Op::Caret),
+            major,
+            minor: None,
+            patch: None,
+        };
+
+        if !self.next_if_dot(cursor) {
+            return Ok(comparator);
+        }
+
+        if self.peek_is_wildcard(*cursor) {
+            return self.parse_wildcard(cursor, op, comparator);
+        }
+
+        comparator.minor = Some(self.parse_number(cursor)?);
+
+        if !self.next_if_dot(cursor) {
+            return Ok(comparator);
+        }
+
+        if self.peek_is_wildcard(*cursor) {
+            return self.parse_wildcard(cursor, op, comparator);
+        }
+
+        comparator.patch = Some(self.parse_number(cursor)?);
+
+        Ok(comparator)
+    }
+
+    fn parse_op(&self, cursor: &mut usize) -> Option<Op> {
+        let (op, len) = match (self.slice.get(*cursor), self.slice.get(*cursor + 1)) {
+            (Some(b'>'), Some(b'=')) => (Op::GreaterEq, 2),
+            (Some(b'<'), Some(b'=')) => (Op::LessEq, 2),
+            (Some(b'='), _) => (Op::Exact, 1),
+            (Some(b'>'), _) => (Op::Greater, 1),
+            (Some(b'<'), _) => (Op::Less, 1),
+            (Some(b'~'), _) => (Op::Tilde, 1),
+            (Some(b'^'), _) => (Op::Caret, 1),
+            _ => return None,
+        };
+
+        *cursor += len;
+
+        Some(op)
+    }
+
+    // Parses a wildcard in the place of the minor or patch component. If the minor component
+    // is a wildcard, the patch component may only be a wildcard as well.
+    fn parse_wildcard(
+        &self,
+        cursor: &mut usize,
+        op: Option<Op>,
+        comparator: Comparator,
+    ) -> Result<Comparator, RequirementParserError> {
+        if op.is_some() {
+            return Err(RequirementParserError::UnexpectedWildcard { at: *cursor });
+        }
+
+        *cursor += 1;
+
+        if comparator.minor.is_none() && self.next_if_dot(cursor) {
+            if !self.peek_is_wildcard(*cursor) {
+                return Err(RequirementParserError::UnexpectedWildcard { at: *cursor - 2 });
+            }
+
+            *cursor += 1;
+        }
+
+        Ok(Comparator {
+            op: Op::Wildcard,
+            ..comparator
+        })
+    }
+
+    fn parse_number(&self, cursor: &mut usize) -> Result<u64, RequirementParserError> {
+        let start = *cursor;
+        let mut iter = self.slice[start..].iter().peekable();
+        let result = parse_component(&mut iter, self.slice.len());
+        *cursor = self.slice.len() - iter.len();
+        let end = start
+            + self.slice[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+
+        result.map_err(|error| match error {
+            ModularParserError::NumberError {
+                error: NumberError::LeadingZero,
+                ..
+            } => RequirementParserError::Numeric {
+                at: start,
+                end,
+                error: NumericError::LeadingZero,
+            },
+            ModularParserError::NumberError {
+                error: NumberError::Overflow,
+                ..
+            } => RequirementParserError::Numeric {
+                at: start,
+                end,
+                error: NumericError::Overflow,
+            },
+            _ if self.peek_is_wildcard(start) => {
+                RequirementParserError::UnexpectedWildcard { at: start }
+            }
+            _ => RequirementParserError::ExpectedNumericToken {
+                at: start,
+                got: self.slice.get(start).copied(),
+            },
+        })
+    }
+
+    fn parse_end(&self, cursor: &mut usize) -> Result<(), RequirementParserError> {
+        self.skip_whitespace(cursor);
+
+        match self.slice.get(*cursor) {
+            Some(&token) => Err(RequirementParserError::ExpectedEndOfInput {
+                at: *cursor,
+                got: token,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn next_if_dot(&self, cursor: &mut usize) -> bool {
+        let is_dot = self.slice.get(*cursor) == Some(&b'.');
+
+        if is_dot {
+            *cursor += 1;
+        }
+
+        is_dot
+    }
+
+    fn peek_is_wildcard(&self, cursor: usize) -> bool {
+        matches!(self.slice.get(cursor), Some(b'*' | b'x' | b'X'))
+    }
+
+    fn skip_whitespace(&self, cursor: &mut usize) {
+        while matches!(self.slice.get(*cursor), Some(b' ' | b'\t')) {
+            *cursor += 1;
+        }
+    }
+
+    fn is_done(&self, cursor: usize) -> bool {
+        cursor >= self.slice.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yare::parameterized;
+
+    #[parameterized(
+        empty = { "", RequirementParserError::Empty },
+        whitespace = { "  ", RequirementParserError::Empty },
+        no_number = { ">=", RequirementParserError::ExpectedNumericToken { at: 2, got: None } },
+        trailing_dot = { "1.", RequirementParserError::ExpectedNumericToken { at: 2, got: None } },
+        trailing_comma = { "1.2,", RequirementParserError::ExpectedNumericToken { at: 4, got: None } },
+        no_comma = { ">=1.2 <1.3", RequirementParserError::ExpectedComma { at: 6, got: b'<' } },
+        four_components = { "1.2.3.4", RequirementParserError::ExpectedComma { at: 5, got: b'.' } },
+        star_and_more = { "*, 1.2", RequirementParserError::ExpectedEndOfInput { at: 1, got: b',' } },
+        wildcard_with_op = { ">=1.*", RequirementParserError::UnexpectedWildcard { at: 4 } },
+        wildcard_major = { "1.2, *", RequirementParserError::UnexpectedWildcard { at: 5 } },
+        number_after_wildcard = { "1.*.3", RequirementParserError::UnexpectedWildcard { at: 2 } },
+        leading_zero = { "1.02", RequirementParserError::Numeric { at: 2, end: 4, error: NumericError::LeadingZero } },
+        overflow = { "18446744073709551616", RequirementParserError::Numeric { at: 0, end: 20, error: NumericError::Overflow } },
+    )]
+    fn rejected(input: &str, expected: RequirementParserError) {
+        let err = Parser::from_slice(input.as_bytes()).parse().unwrap_err();
+
+        assert_eq!(err, expected);
+    }
+
+    #[test]
+    fn accepts_max() {
+        let input = format!("={}.{}.{}", u64::MAX, u64::MAX, u64::MAX);
+        let requirement = Parser::from_slice(input.as_bytes()).parse().unwrap();
+
+        assert_eq!(
+            requirement.comparators,
+            vec![Comparator {
+                op: Op::Exact,
+                major: u64::MAX,
+                minor: Some(u64::MAX),
+                patch: Some(u64::MAX),
+            }]
+        );
+    }
+}
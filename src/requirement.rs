@@ -0,0 +1,403 @@
+use crate::parsers::requirement::{Parser, RequirementParserError};
+use crate::{FullVersion, Version};
+use std::fmt;
+use std::str::FromStr;
+
+/// A version requirement, which consists of zero or more comparators, such as `>=1.56, <1.70`.
+///
+/// A version matches the requirement if it matches each of its [`Comparator`]s. A requirement
+/// without any comparators, written as `*`, matches every version.
+///
+/// # Syntax
+///
+/// The accepted syntax follows the requirement syntax of Cargo. Comparators are separated by a
+/// comma, and consist of an optional operator, followed by a one-, two- or three-component
+/// version, where trailing components may be omitted. See [`Op`] for the supported operators, and
+/// how they treat omitted components. A comparator without an operator is a caret requirement.
+///
+/// # Two component versions
+///
+/// When matching, a two component [`Version::Base`] `major.minor` version is treated as if it
+/// were the three component version `major.minor.0`. For example, `1.2` matches `>=1.2.0` and
+/// `^1.1`, but it does not match `>=1.2.1`.
+///
+/// # Example
+///
+/// ```
+/// use version_number::{Version, VersionReq};
+///
+/// let requirement = VersionReq::parse(">=1.56, <1.70").unwrap();
+///
+/// assert!(requirement.matches(&Version::parse("1.56").unwrap()));
+/// assert!(requirement.matches(&Version::parse("1.69.2").unwrap()));
+/// assert!(!requirement.matches(&Version::parse("1.70.0").unwrap()));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct VersionReq {
+    /// The comparators which a version must all match.
+    pub comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// A requirement which matches any version, written as `*`.
+    pub const STAR: VersionReq = VersionReq {
+        comparators: Vec::new(),
+    };
+
+    /// Parse a version requirement, such as `>=1.56, <1.70`, from a given input.
+    ///
+    /// Returns a [`RequirementParserError`] if it fails to parse.
+    ///
+    /// [`RequirementParserError`]: crate::parsers::requirement::RequirementParserError
+    pub fn parse(input: &str) -> Result<Self, RequirementParserError> {
+        Parser::from_slice(input.as_bytes()).parse()
+    }
+
+    /// Check whether the given `version` matches each of the comparators of this requirement.
+    ///
+    /// A two component [`Version::Base`] is matched as if its `patch` component were `0`.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators
+            .iter()
+            .all(|comparator| comparator.matches(version))
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = RequirementParserError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut comparators = self.comparators.iter();
+
+        match comparators.next() {
+            Some(first) => fmt::Display::fmt(first, f)?,
+            None => return f.write_str("*"),
+        }
+
+        for comparator in comparators {
+            f.write_fmt(format_args!(", {}", comparator))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single version comparator of a [`VersionReq`], such as `>=1.56` or `~1.2.3`.
+///
+/// The `minor` and `patch` components are optional. How an omitted component is treated,
+/// depends on the [`Op`]erator.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Comparator {
+    /// The operator used to compare a version against the components of this comparator.
+    pub op: Op,
+    /// The `major` component.
+    pub major: u64,
+    /// The `minor` component, if specified.
+    pub minor: Option<u64>,
+    /// The `patch` component, if specified.
+    ///
+    /// May only be present if the `minor` component is present as well.
+    pub patch: Option<u64>,
+}
+
+impl Comparator {
+    /// Parse a single comparator, such as `>=1.56`, from a given input.
+    ///
+    /// Returns a [`RequirementParserError`] if it fails to parse, or if the input consists of
+    /// more than one comparator.
+    ///
+    /// [`RequirementParserError`]: crate::parsers::requirement::RequirementParserError
+    pub fn parse(input: &str) -> Result<Self, RequirementParserError> {
+        Parser::from_slice(input.as_bytes()).parse_comparator()
+    }
+
+    /// Check whether the given `version` matches this comparator.
+    ///
+    /// A two component [`Version::Base`] is matched as if its `patch` component were `0`.
+    pub fn matches(&self, version: &Version) -> bool {
+        let version = match version {
+            Version::Base(base) => base.to_full_version_lossy(),
+            Version::Full(full) => *full,
+        };
+
+        match self.op {
+            Op::Exact | Op::Wildcard => self.matches_exact(&version),
+            Op::Greater => self.matches_greater(&version),
+            Op::GreaterEq => self.matches_exact(&version) || self.matches_greater(&version),
+            Op::Less => self.matches_less(&version),
+            Op::LessEq => self.matches_exact(&version) || self.matches_less(&version),
+            Op::Tilde => self.matches_tilde(&version),
+            Op::Caret => self.matches_caret(&version),
+        }
+    }
+
+    fn matches_exact(&self, version: &FullVersion) -> bool {
+        version.major == self.major
+            && self.minor.map_or(true, |minor| version.minor == minor)
+            && self.patch.map_or(true, |patch| version.patch == patch)
+    }
+
+    fn matches_greater(&self, version: &FullVersion) -> bool {
+        if version.major != self.major {
+            return version.major > self.major;
+        }
+
+        match self.minor {
+            Some(minor) if version.minor != minor => return version.minor > minor,
+            Some(_) => {}
+            None => return false,
+        }
+
+        match self.patch {
+            Some(patch) => version.patch > patch,
+            None => false,
+        }
+    }
+
+    fn matches_less(&self, version: &FullVersion) -> bool {
+        if version.major != self.major {
+            return version.major < self.major;
+        }
+
+        match self.minor {
+            Some(minor) if version.minor != minor => return version.minor < minor,
+            Some(_) => {}
+            None => return false,
+        }
+
+        match self.patch {
+            Some(patch) => version.patch < patch,
+            None => false,
+        }
+    }
+
+    fn matches_tilde(&self, version: &FullVersion) -> bool {
+        version.major == self.major
+            && self.minor.map_or(true, |minor| version.minor == minor)
+            && self.patch.map_or(true, |patch| version.patch >= patch)
+    }
+
+    fn matches_caret(&self, version: &FullVersion) -> bool {
+        if version.major != self.major {
+            return false;
+        }
+
+        let minor = match self.minor {
+            Some(minor) => minor,
+            None => return true,
+        };
+
+        let patch = match self.patch {
+            Some(patch) => patch,
+            None if self.major > 0 => return version.minor >= minor,
+            None => return version.minor == minor,
+        };
+
+        if self.major > 0 {
+            (version.minor, version.patch) >= (minor, patch)
+        } else if minor > 0 {
+            version.minor == minor && version.patch >= patch
+        } else {
+            version.minor == minor && version.patch == patch
+        }
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = RequirementParserError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.op, f)?;
+        f.write_fmt(format_args!("{}", self.major))?;
+
+        if let Some(minor) = self.minor {
+            f.write_fmt(format_args!(".{}", minor))?;
+        }
+
+        if let Some(patch) = self.patch {
+            f.write_fmt(format_args!(".{}", patch))?;
+        }
+
+        if matches!(self.op, Op::Wildcard) {
+            f.write_str(".*")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The operator of a [`Comparator`].
+///
+/// The table below shows how each operator treats a comparator where trailing components
+/// have been omitted, in terms of the [`Op::GreaterEq`] and [`Op::Less`] operators.
+///
+/// | Operator     | Example  | Equivalent to      |
+/// |--------------|----------|--------------------|
+/// | `=`          | `=1.2`   | `>=1.2.0, <1.3.0`  |
+/// | `>`          | `>1.2`   | `>=1.3.0`          |
+/// | `>=`         | `>=1.2`  | `>=1.2.0`          |
+/// | `<`          | `<1.2`   | `<1.2.0`           |
+/// | `<=`         | `<=1.2`  | `<1.3.0`           |
+/// | `~`          | `~1.2.3` | `>=1.2.3, <1.3.0`  |
+/// | `^`          | `^1.2.3` | `>=1.2.3, <2.0.0`  |
+/// | `^`          | `^0.2.3` | `>=0.2.3, <0.3.0`  |
+/// | `^`          | `^0.0.3` | `>=0.0.3, <0.0.4`  |
+/// | wildcard     | `1.2.*`  | `>=1.2.0, <1.3.0`  |
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Op {
+    /// The `=` operator: the version must equal each of the specified components.
+    Exact,
+    /// The `>` operator: the version must be greater than the specified components.
+    Greater,
+    /// The `>=` operator: the version must be greater than, or equal to, the specified components.
+    GreaterEq,
+    /// The `<` operator: the version must be less than the specified components.
+    Less,
+    /// The `<=` operator: the version must be less than, or equal to, the specified components.
+    LessEq,
+    /// The `~` operator: only allows changes to the `patch` component, when the `minor` component
+    /// is specified, and changes to the `minor` and `patch` components otherwise.
+    Tilde,
+    /// The `^` operator: allows changes which do not modify the left-most non-zero specified
+    /// component. This is the default operator when no operator is given.
+    Caret,
+    /// A wildcard in the place of the first omitted component, such as `1.*` or `1.2.*`.
+    Wildcard,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+            Op::Wildcard => "",
+        };
+
+        f.write_str(op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Comparator, Op, Version, VersionReq};
+
+    #[yare::parameterized(
+        star = { "*", "*" },
+        exact = { "=1.2.3", "=1.2.3" },
+        greater = { ">1.2", ">1.2" },
+        greater_eq = { ">=1", ">=1" },
+        less = { "<1.2.3", "<1.2.3" },
+        less_eq = { "<=1.2", "<=1.2" },
+        tilde = { "~1.2", "~1.2" },
+        caret = { "^1.2.3", "^1.2.3" },
+        implicit_caret = { "1.2", "^1.2" },
+        wildcard_minor = { "1.*", "1.*" },
+        wildcard_patch = { "1.2.x", "1.2.*" },
+        wildcard_minor_and_patch = { "1.*.*", "1.*" },
+        multiple = { ">=1.56,<1.70", ">=1.56, <1.70" },
+        whitespace = { " >= 1.56 ,  < 1.70 ", ">=1.56, <1.70" },
+    )]
+    fn display(input: &str, expected: &str) {
+        let requirement = VersionReq::parse(input).unwrap();
+
+        assert_eq!(requirement.to_string(), expected);
+        assert_eq!(VersionReq::parse(expected).unwrap(), requirement);
+    }
+
+    #[test]
+    fn parse_comparator() {
+        let comparator = Comparator::parse(">=1.56").unwrap();
+
+        assert_eq!(
+            comparator,
+            Comparator {
+                op: Op::GreaterEq,
+                major: 1,
+                minor: Some(56),
+                patch: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_comparator_rejects_multiple() {
+        assert!(Comparator::parse(">=1.56, <1.70").is_err());
+    }
+
+    #[yare::parameterized(
+        star_base = { "*", "0.0" },
+        star_full = { "*", "18446744073709551615.0.0" },
+        exact_full = { "=1.2.3", "1.2.3" },
+        exact_partial = { "=1.2", "1.2.9" },
+        exact_major = { "=1", "1.9.9" },
+        greater_patch = { ">1.2.3", "1.2.4" },
+        greater_minor = { ">1.2", "1.3.0" },
+        greater_major = { ">1", "2.0" },
+        greater_eq_same = { ">=1.2.3", "1.2.3" },
+        greater_eq_base = { ">=1.2.0", "1.2" },
+        greater_eq_partial = { ">=1.2", "1.2.0" },
+        less_patch = { "<1.2.3", "1.2.2" },
+        less_minor = { "<1.2", "1.1.99" },
+        less_eq_partial = { "<=1.2", "1.2.99" },
+        tilde_full = { "~1.2.3", "1.2.99" },
+        tilde_minor = { "~1.2", "1.2.0" },
+        tilde_major = { "~1", "1.99.0" },
+        caret_full = { "^1.2.3", "1.99.0" },
+        caret_minor = { "^1.2", "1.3" },
+        caret_zero_minor = { "^0.2.3", "0.2.4" },
+        caret_zero_patch = { "^0.0.3", "0.0.3" },
+        caret_zero_base = { "^0.0", "0.0.99" },
+        wildcard_minor = { "1.*", "1.99.99" },
+        wildcard_patch = { "1.2.*", "1.2" },
+        range = { ">=1.56, <1.70", "1.69.99" },
+    )]
+    fn matches(requirement: &str, version: &str) {
+        let requirement = VersionReq::parse(requirement).unwrap();
+        let version = Version::parse(version).unwrap();
+
+        assert!(requirement.matches(&version));
+    }
+
+    #[yare::parameterized(
+        exact_full = { "=1.2.3", "1.2.4" },
+        exact_partial = { "=1.2", "1.3.0" },
+        greater_same = { ">1.2.3", "1.2.3" },
+        greater_minor = { ">1.2", "1.2.99" },
+        greater_eq_base = { ">=1.2.1", "1.2" },
+        less_same = { "<1.2.3", "1.2.3" },
+        less_minor = { "<1.2", "1.2.0" },
+        less_eq_partial = { "<=1.2", "1.3" },
+        tilde_full = { "~1.2.3", "1.3.0" },
+        tilde_lower = { "~1.2.3", "1.2.2" },
+        caret_full = { "^1.2.3", "2.0.0" },
+        caret_lower = { "^1.2.3", "1.2" },
+        caret_zero_minor = { "^0.2.3", "0.3.0" },
+        caret_zero_patch = { "^0.0.3", "0.0.4" },
+        caret_zero_base = { "^0.0", "0.1" },
+        wildcard_minor = { "1.*", "2.0" },
+        range = { ">=1.56, <1.70", "1.70" },
+    )]
+    fn does_not_match(requirement: &str, version: &str) {
+        let requirement = VersionReq::parse(requirement).unwrap();
+        let version = Version::parse(version).unwrap();
+
+        assert!(!requirement.matches(&version));
+    }
+}
@@ -0,0 +1,434 @@
+//! Parse the output of `rustc --version` and `rustc -vV`.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::rustc::{ReleaseChannel, RustcVersionInfo};
+//! use version_number::FullVersion;
+//!
+//! let output = "\
+//! rustc 1.70.0 (90c541806 2023-05-31)
+//! binary: rustc
+//! commit-hash: 90c541806f23a127002de5b4038be731ba1458ca
+//! commit-date: 2023-05-31
+//! host: x86_64-unknown-linux-gnu
+//! release: 1.70.0
+//! LLVM version: 16.0.2
+//! ";
+//!
+//! let info = RustcVersionInfo::parse(output).unwrap();
+//!
+//! assert_eq!(info.release, FullVersion::new(1, 70, 0));
+//! assert_eq!(info.channel, ReleaseChannel::Stable);
+//! assert_eq!(info.host.as_deref(), Some("x86_64-unknown-linux-gnu"));
+//! assert_eq!(info.llvm_version, Some(FullVersion::new(16, 0, 2)));
+//! ```
+
+use crate::parsers::original::OriginalParser;
+use crate::toolchain::Date;
+use crate::{FullVersion, FullVersionParser, ParserError};
+use std::fmt;
+use std::str::FromStr;
+
+/// The version information of a `rustc` binary, as printed by `rustc --version`, or in more
+/// detail by `rustc -vV`.
+///
+/// The fields which are only printed by `rustc -vV` are `None` when parsing the output of
+/// `rustc --version`. Rustc builds without git information print `unknown` for the commit hash
+/// and date, which are parsed as `None` as well.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RustcVersionInfo {
+    /// The release version, e.g. `1.70.0`, without the channel suffix.
+    pub release: FullVersion,
+    /// The release channel, derived from the suffix of the release, e.g. `-nightly`.
+    pub channel: ReleaseChannel,
+    /// The commit hash of the build. This is the abbreviated hash, unless parsed from the
+    /// `commit-hash` field of `rustc -vV`.
+    pub commit_hash: Option<String>,
+    /// The commit date of the build.
+    pub commit_date: Option<Date>,
+    /// The host triple of the compiler, e.g. `x86_64-unknown-linux-gnu`.
+    pub host: Option<String>,
+    /// The version of LLVM used by the compiler.
+    pub llvm_version: Option<FullVersion>,
+}
+
+impl RustcVersionInfo {
+    /// Parse the output of either `rustc --version` or `rustc -vV`.
+    pub fn parse(output: &str) -> Result<Self, RustcVersionError> {
+        let mut lines = output.lines().filter(|line| !line.trim().is_empty());
+
+        let first = lines.next().ok_or(RustcVersionError::Empty)?;
+        let mut info = parse_version_line(first.trim())?;
+
+        for line in lines {
+            let (key, value) = split_field(line)?;
+
+            match key {
+                "commit-hash" => info.commit_hash = known(value).map(str::to_string),
+                "commit-date" => info.commit_date = known(value).map(parse_date).transpose()?,
+                "host" => info.host = Some(value.to_string()),
+                "release" => {
+                    let (release, channel) = parse_release(value)?;
+                    info.release = release;
+                    info.channel = channel;
+                }
+                "LLVM version" => {
+                    let version = OriginalParser
+                        .parse_full(value)
+                        .map_err(RustcVersionError::LlvmVersion)?;
+                    info.llvm_version = Some(version);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+impl FromStr for RustcVersionInfo {
+    type Err = RustcVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// The release channel of a `rustc` binary.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ReleaseChannel {
+    /// A stable release, which has no suffix.
+    Stable,
+    /// A beta release, with the `-beta` or `-beta.N` suffix.
+    Beta,
+    /// A nightly release, with the `-nightly` suffix.
+    Nightly,
+    /// A locally built compiler, with the `-dev` suffix.
+    Dev,
+}
+
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+            Self::Nightly => "nightly",
+            Self::Dev => "dev",
+        })
+    }
+}
+
+/// Parse the `rustc <release> (<hash> <date>)` line.
+fn parse_version_line(line: &str) -> Result<RustcVersionInfo, RustcVersionError> {
+    let rest = line
+        .strip_prefix("rustc ")
+        .ok_or_else(|| RustcVersionError::ExpectedRustc {
+            line: line.to_string(),
+        })?;
+
+    let (release, details) = match rest.find(' ') {
+        Some(at) => (&rest[..at], rest[at..].trim()),
+        None => (rest, ""),
+    };
+
+    let (release, channel) = parse_release(release)?;
+
+    // The details are absent, or not of the `(<hash> <date>)` form, for builds without git
+    // information, e.g. `(built from a source tarball)`. Only the first group is read, since
+    // distributions may append their own, e.g. `(Fedora 1.70.0-1.fc38)`.
+    let (commit_hash, commit_date) = match details
+        .strip_prefix('(')
+        .and_then(|details| details.find(')').map(|end| &details[..end]))
+        .and_then(|details| {
+            let mut parts = details.split(' ');
+            Some((parts.next()?, parts.next()?, parts.next()))
+        }) {
+        Some((hash, date, None)) => match Date::parse(date) {
+            Ok(date) => (Some(hash.to_string()), Some(date)),
+            Err(_) => (None, None),
+        },
+        _ => (None, None),
+    };
+
+    Ok(RustcVersionInfo {
+        release,
+        channel,
+        commit_hash,
+        commit_date,
+        host: None,
+        llvm_version: None,
+    })
+}
+
+/// Parse a release like `1.70.0`, `1.71.0-beta.3` or `1.72.0-nightly`.
+fn parse_release(release: &str) -> Result<(FullVersion, ReleaseChannel), RustcVersionError> {
+    let (version, suffix) = match release.find('-') {
+        Some(at) => (&release[..at], Some(&release[at + 1..])),
+        None => (release, None),
+    };
+
+    let version = OriginalParser
+        .parse_full(version)
+        .map_err(RustcVersionError::Release)?;
+
+    let channel = match suffix {
+        None => ReleaseChannel::Stable,
+        Some("nightly") => ReleaseChannel::Nightly,
+        Some("dev") => ReleaseChannel::Dev,
+        Some(beta) if beta == "beta" || beta.starts_with("beta.") => ReleaseChannel::Beta,
+        Some(other) => {
+            return Err(RustcVersionError::UnknownChannel {
+                suffix: other.to_string(),
+            })
+        }
+    };
+
+    Ok((version, channel))
+}
+
+fn parse_date(date: &str) -> Result<Date, RustcVersionError> {
+    Date::parse(date).map_err(|_| RustcVersionError::InvalidCommitDate {
+        date: date.to_string(),
+    })
+}
+
+/// Split a `key: value` line.
+fn split_field(line: &str) -> Result<(&str, &str), RustcVersionError> {
+    match line.find(':') {
+        Some(at) => Ok((line[..at].trim(), line[at + 1..].trim())),
+        None => Err(RustcVersionError::MalformedLine {
+            line: line.to_string(),
+        }),
+    }
+}
+
+/// Rustc builds without git information print `unknown` instead of the commit hash and date.
+fn known(value: &str) -> Option<&str> {
+    match value {
+        "unknown" => None,
+        value => Some(value),
+    }
+}
+
+/// Errors which may occur when parsing the output of `rustc --version` or `rustc -vV`.
+#[derive(Debug, thiserror::Error)]
+pub enum RustcVersionError {
+    /// The output is empty.
+    #[error("The rustc version output is empty")]
+    Empty,
+
+    /// The first line does not start with `rustc `.
+    #[error("Expected the first line to start with 'rustc ', but got '{line}'")]
+    ExpectedRustc {
+        /// The unexpected first line.
+        line: String,
+    },
+
+    /// The release is not a valid `major.minor.patch` version.
+    #[error("Invalid rustc release version: {0}")]
+    Release(#[source] ParserError),
+
+    /// The suffix of the release is not a known release channel.
+    #[error("Unknown rustc release channel '{suffix}'")]
+    UnknownChannel {
+        /// The unknown suffix, without the leading `-`.
+        suffix: String,
+    },
+
+    /// The commit date is not a valid `YYYY-MM-DD` date.
+    #[error("Invalid rustc commit date '{date}', expected a date of the form 'YYYY-MM-DD'")]
+    InvalidCommitDate {
+        /// The invalid date.
+        date: String,
+    },
+
+    /// The LLVM version is not a valid `major.minor.patch` version.
+    #[error("Invalid LLVM version: {0}")]
+    LlvmVersion(#[source] ParserError),
+
+    /// A line of the verbose output is not of the `key: value` form.
+    #[error("Expected a 'key: value' line, but got '{line}'")]
+    MalformedLine {
+        /// The malformed line.
+        line: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::NumericError;
+
+    const STABLE_VERBOSE: &str = "\
+rustc 1.70.0 (90c541806 2023-05-31)
+binary: rustc
+commit-hash: 90c541806f23a127002de5b4038be731ba1458ca
+commit-date: 2023-05-31
+host: x86_64-unknown-linux-gnu
+release: 1.70.0
+LLVM version: 16.0.2
+";
+
+    const NIGHTLY_VERBOSE: &str = "\
+rustc 1.72.0-nightly (5ea666864 2023-06-27)
+binary: rustc
+commit-hash: 5ea66686467d3ec5f8c81570e7f0f16ad8dd8cc3
+commit-date: 2023-06-27
+host: aarch64-apple-darwin
+release: 1.72.0-nightly
+LLVM version: 16.0.5
+";
+
+    const SOURCE_BUILD_VERBOSE: &str = "\
+rustc 1.71.0-dev
+binary: rustc
+commit-hash: unknown
+commit-date: unknown
+host: x86_64-unknown-freebsd
+release: 1.71.0-dev
+LLVM version: 16.0.5
+";
+
+    #[test]
+    fn stable_verbose() {
+        let info = RustcVersionInfo::parse(STABLE_VERBOSE).unwrap();
+
+        assert_eq!(
+            info,
+            RustcVersionInfo {
+                release: FullVersion::new(1, 70, 0),
+                channel: ReleaseChannel::Stable,
+                commit_hash: Some("90c541806f23a127002de5b4038be731ba1458ca".to_string()),
+                commit_date: Some(Date::new(2023, 5, 31)),
+                host: Some("x86_64-unknown-linux-gnu".to_string()),
+                llvm_version: Some(FullVersion::new(16, 0, 2)),
+            }
+        );
+    }
+
+    #[test]
+    fn nightly_verbose() {
+        let info = RustcVersionInfo::parse(NIGHTLY_VERBOSE).unwrap();
+
+        assert_eq!(info.release, FullVersion::new(1, 72, 0));
+        assert_eq!(info.channel, ReleaseChannel::Nightly);
+        assert_eq!(info.commit_date, Some(Date::new(2023, 6, 27)));
+        assert_eq!(info.host.as_deref(), Some("aarch64-apple-darwin"));
+    }
+
+    #[test]
+    fn source_build_verbose() {
+        let info = RustcVersionInfo::parse(SOURCE_BUILD_VERBOSE).unwrap();
+
+        assert_eq!(info.release, FullVersion::new(1, 71, 0));
+        assert_eq!(info.channel, ReleaseChannel::Dev);
+        assert_eq!(info.commit_hash, None);
+        assert_eq!(info.commit_date, None);
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let output = STABLE_VERBOSE.replace('\n', "\r\n");
+        let info = RustcVersionInfo::parse(&output).unwrap();
+
+        assert_eq!(info, RustcVersionInfo::parse(STABLE_VERBOSE).unwrap());
+    }
+
+    #[yare::parameterized(
+        stable = { "rustc 1.70.0 (90c541806 2023-05-31)", FullVersion::new(1, 70, 0), ReleaseChannel::Stable, Some("90c541806"), Some(Date::new(2023, 5, 31)) },
+        beta = { "rustc 1.71.0-beta.3 (7ad7f9b2b 2023-06-17)", FullVersion::new(1, 71, 0), ReleaseChannel::Beta, Some("7ad7f9b2b"), Some(Date::new(2023, 6, 17)) },
+        nightly = { "rustc 1.72.0-nightly (5ea666864 2023-06-27)\n", FullVersion::new(1, 72, 0), ReleaseChannel::Nightly, Some("5ea666864"), Some(Date::new(2023, 6, 27)) },
+        without_details = { "rustc 1.70.0", FullVersion::new(1, 70, 0), ReleaseChannel::Stable, None, None },
+        source_tarball = { "rustc 1.70.0 (built from a source tarball)", FullVersion::new(1, 70, 0), ReleaseChannel::Stable, None, None },
+        vendor_details = { "rustc 1.70.0 (90c541806 2023-05-31) (Fedora 1.70.0-1.fc38)", FullVersion::new(1, 70, 0), ReleaseChannel::Stable, Some("90c541806"), Some(Date::new(2023, 5, 31)) },
+        unclosed_details = { "rustc 1.70.0 (90c541806 2023-05-31", FullVersion::new(1, 70, 0), ReleaseChannel::Stable, None, None },
+    )]
+    fn version_line(
+        output: &str,
+        release: FullVersion,
+        channel: ReleaseChannel,
+        commit_hash: Option<&str>,
+        commit_date: Option<Date>,
+    ) {
+        let info = RustcVersionInfo::parse(output).unwrap();
+
+        assert_eq!(info.release, release);
+        assert_eq!(info.channel, channel);
+        assert_eq!(info.commit_hash.as_deref(), commit_hash);
+        assert_eq!(info.commit_date, commit_date);
+        assert_eq!(info.host, None);
+        assert_eq!(info.llvm_version, None);
+    }
+
+    #[yare::parameterized(
+        empty = { "" },
+        whitespace = { " \n\n" },
+    )]
+    fn empty(output: &str) {
+        assert!(matches!(
+            RustcVersionInfo::parse(output),
+            Err(RustcVersionError::Empty)
+        ));
+    }
+
+    #[yare::parameterized(
+        cargo = { "cargo 1.70.0 (ec8a8a0ca 2023-04-25)" },
+        no_release = { "rustc" },
+    )]
+    fn expected_rustc(output: &str) {
+        assert!(matches!(
+            RustcVersionInfo::parse(output),
+            Err(RustcVersionError::ExpectedRustc { .. })
+        ));
+    }
+
+    #[yare::parameterized(
+        base_version = { "rustc 1.70" },
+        leading_zero = { "rustc 1.070.0" },
+    )]
+    fn invalid_release(output: &str) {
+        assert!(matches!(
+            RustcVersionInfo::parse(output),
+            Err(RustcVersionError::Release(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_channel() {
+        assert!(matches!(
+            RustcVersionInfo::parse("rustc 1.70.0-alpha"),
+            Err(RustcVersionError::UnknownChannel { suffix }) if suffix == "alpha"
+        ));
+    }
+
+    #[test]
+    fn invalid_commit_date() {
+        let output = STABLE_VERBOSE.replace("commit-date: 2023-05-31", "commit-date: 2023-5-31");
+
+        assert!(matches!(
+            RustcVersionInfo::parse(&output),
+            Err(RustcVersionError::InvalidCommitDate { date }) if date == "2023-5-31"
+        ));
+    }
+
+    #[test]
+    fn invalid_llvm_version() {
+        let output = STABLE_VERBOSE.replace("LLVM version: 16.0.2", "LLVM version: 16.00.2");
+
+        assert!(matches!(
+            RustcVersionInfo::parse(&output),
+            Err(RustcVersionError::LlvmVersion(error)) if *error.reason() == NumericError::LeadingZero.into()
+        ));
+    }
+
+    #[test]
+    fn malformed_line() {
+        let output = format!("{}garbage\n", STABLE_VERBOSE);
+
+        assert!(matches!(
+            RustcVersionInfo::parse(&output),
+            Err(RustcVersionError::MalformedLine { line }) if line == "garbage"
+        ));
+    }
+}
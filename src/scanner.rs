@@ -0,0 +1,340 @@
+//! Find the version numbers which occur in free text, such as logs, READMEs or CI output.
+//!
+//! A [`Scanner`] yields the [`Span`] and [`Version`] of each version number in its input, from
+//! left to right. Versions are found in runs of numbers which are separated by single dots, such
+//! as `1.65` or `192.168.1.1`. A run is only a version if it consists of two or three components,
+//! so no version is found within `192.168.1.1`. Components with leading zeros, or which overflow
+//! a `u64`, are rejected, as by the parsers.
+//!
+//! The [`Boundary`] determines which bytes may directly precede or follow a version. By default,
+//! a version may not be adjacent to an ASCII alphanumeric character or `_`, so no version is
+//! found within `x1.2y`, while a `v` or `V` prefix, as in `v1.2.3`, is allowed. A dot which is
+//! not followed by a digit may follow a version, so `1.65` is found in `Rust 1.65.` and
+//! `foo-1.2.3.tar.gz`.
+//!
+//! The scanner does not allocate, and skips to the next digit of the input, so it is suitable
+//! for large inputs.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::parsers::Span;
+//! use version_number::scanner::Scanner;
+//! use version_number::Version;
+//!
+//! let input = "requires Rust 1.65 or newer, tested on v1.70.0 (not 192.168.1.1)";
+//! let versions = Scanner::new().scan(input).collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     versions,
+//!     vec![
+//!         (Span::new(14, 18), Version::new_base_version(1, 65)),
+//!         (Span::new(40, 46), Version::new_full_version(1, 70, 0)),
+//!     ]
+//! );
+//! ```
+
+use crate::parsers::modular::component::starts_with_patch;
+use crate::parsers::modular::Parser;
+use crate::parsers::Span;
+use crate::{Variant, Version};
+use core::fmt;
+
+/// Finds version numbers in free text.
+///
+/// By default, a scanner finds both two and three component versions, which are delimited by a
+/// [`Boundary::Word`], and which may be prefixed by a `v` or `V`.
+#[derive(Copy, Clone, Debug)]
+pub struct Scanner {
+    boundary: Boundary,
+    variant: Option<Variant>,
+    v_prefix: bool,
+}
+
+impl Scanner {
+    /// Create a scanner with the default configuration.
+    pub fn new() -> Self {
+        Self {
+            boundary: Boundary::Word,
+            variant: None,
+            v_prefix: true,
+        }
+    }
+
+    /// Set the rule which determines which bytes may directly precede or follow a version.
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Only find versions of the given variant, i.e. only two component [`Version::Base`]
+    /// versions, or only three component [`Version::Full`] versions.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Whether a version may be prefixed by a `v` or `V`, even if the boundary does not allow
+    /// a letter to precede a version. The prefix is not part of the span of a match.
+    pub fn with_v_prefix(mut self, allowed: bool) -> Self {
+        self.v_prefix = allowed;
+        self
+    }
+
+    /// Find the versions in the `input`, as an iterator of their spans and values.
+    pub fn scan<'i, B: AsRef<[u8]> + ?Sized>(&self, input: &'i B) -> Matches<'i> {
+        Matches {
+            scanner: *self,
+            input: input.as_ref(),
+            at: 0,
+        }
+    }
+
+    // Find the run of components which starts with the digit at `start`, and return the end of
+    // the run, and the version, if the run is one.
+    fn match_run(&self, input: &[u8], start: usize) -> (usize, Option<Version>) {
+        let mut end = start + digits(&input[start..]);
+        let mut components = 1;
+
+        while starts_with_patch(&input[end..]) {
+            end += 1 + digits(&input[end + 1..]);
+            components += 1;
+        }
+
+        let is_version = matches!(components, 2 | 3)
+            && self.is_boundary_before(input, start)
+            && input.get(end).map_or(true, |&b| !self.boundary.forbids(b));
+
+        let version = if is_version {
+            Parser::from_slice(&input[start..end])
+                .parse()
+                .ok()
+                .filter(|version| self.accepts(version))
+        } else {
+            None
+        };
+
+        (end, version)
+    }
+
+    // Find the version which starts with the digit at `start`, regardless of the bytes which
+    // surround it, and return its end, or the end of the number at `start` if there is none.
+    fn match_greedy(&self, input: &[u8], start: usize) -> (usize, Option<Version>) {
+        let parser = Parser::from_slice(&input[start..]);
+
+        let prefix = match self.variant {
+            None => parser.parse_prefix().ok(),
+            Some(Variant::Base) => parser
+                .parse_base()
+                .ok()
+                .map(|parser| parser.finish_prefix().map(Version::Base)),
+            Some(Variant::Full) => parser
+                .parse_full()
+                .ok()
+                .map(|parser| parser.finish_prefix().map(Version::Full)),
+        };
+
+        match prefix {
+            Some(prefix) => (start + prefix.consumed, Some(prefix.value)),
+            None => (start + digits(&input[start..]), None),
+        }
+    }
+
+    fn is_boundary_before(&self, input: &[u8], start: usize) -> bool {
+        match start.checked_sub(1).map(|i| (i, input[i])) {
+            None => true,
+            Some((i, b'v' | b'V')) if self.v_prefix => i
+                .checked_sub(1)
+                .map_or(true, |i| !self.boundary.forbids(input[i])),
+            Some((_, b)) => !self.boundary.forbids(b),
+        }
+    }
+
+    fn accepts(&self, version: &Version) -> bool {
+        self.variant.map_or(true, |variant| version.is(variant))
+    }
+}
+
+impl Default for Scanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The rule which determines which bytes may directly precede or follow a version.
+#[derive(Copy, Clone)]
+pub enum Boundary {
+    /// Versions may be surrounded by any byte, so `1.2` is found in `x1.2y`.
+    ///
+    /// The versions are found greedily, from left to right: within `192.168.1.1`, the version
+    /// `192.168.1` is found.
+    None,
+    /// Versions may not be preceded or followed by an ASCII alphanumeric character or `_`, and
+    /// must be a complete run of dot separated numbers.
+    Word,
+    /// Like [`Boundary::Word`], but the given function determines which bytes may not precede or
+    /// follow a version, by returning `true` for them.
+    Custom(fn(u8) -> bool),
+}
+
+impl Boundary {
+    // Whether the byte `b` may not directly precede or follow a version.
+    fn forbids(&self, b: u8) -> bool {
+        match self {
+            Self::None => false,
+            Self::Word => b.is_ascii_alphanumeric() || b == b'_',
+            Self::Custom(forbids) => forbids(b),
+        }
+    }
+}
+
+impl fmt::Debug for Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::Word => f.write_str("Word"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// An iterator over the versions found by a [`Scanner`], with their spans.
+///
+/// Created by [`Scanner::scan`].
+#[derive(Clone, Debug)]
+pub struct Matches<'i> {
+    scanner: Scanner,
+    input: &'i [u8],
+    at: usize,
+}
+
+impl Iterator for Matches<'_> {
+    type Item = (Span, Version);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(offset) = self.input[self.at..].iter().position(u8::is_ascii_digit) {
+            let start = self.at + offset;
+
+            let (end, version) = match self.scanner.boundary {
+                Boundary::None => self.scanner.match_greedy(self.input, start),
+                Boundary::Word | Boundary::Custom(_) => self.scanner.match_run(self.input, start),
+            };
+
+            self.at = end;
+
+            if let Some(version) = version {
+                return Some((Span::new(start, end), version));
+            }
+        }
+
+        self.at = self.input.len();
+        None
+    }
+}
+
+// The number of digits at the start of the input.
+fn digits(input: &[u8]) -> usize {
+    input.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(scanner: Scanner, input: &str) -> Vec<(usize, usize, Version)> {
+        scanner
+            .scan(input)
+            .map(|(span, version)| (span.start, span.end, version))
+            .collect()
+    }
+
+    #[yare::parameterized(
+        empty = { "", &[] },
+        no_digits = { "no versions here", &[] },
+        sentence = { "requires Rust 1.65 or newer", &[(14, 18, Version::new_base_version(1, 65))] },
+        full = { "rustc 1.70.0 (90c541806 2023-05-31)", &[(6, 12, Version::new_full_version(1, 70, 0))] },
+        end_of_sentence = { "Rust 1.65.", &[(5, 9, Version::new_base_version(1, 65))] },
+        archive = { "foo-1.2.3.tar.gz", &[(4, 9, Version::new_full_version(1, 2, 3))] },
+        v_prefix = { "v1.2 and V3.4", &[(1, 4, Version::new_base_version(1, 2)), (10, 13, Version::new_base_version(3, 4))] },
+        ip_address = { "192.168.1.1", &[] },
+        inside_word = { "x1.2y", &[] },
+        prefixed_word = { "av1.2", &[] },
+        single_number = { "1 2 3", &[] },
+        leading_zero = { "22.04 and 1.2", &[(10, 13, Version::new_base_version(1, 2))] },
+        overflow = { "18446744073709551616.0", &[] },
+        many = { "1.0, 1.2.3; (2.0)", &[
+            (0, 3, Version::new_base_version(1, 0)),
+            (5, 10, Version::new_full_version(1, 2, 3)),
+            (13, 16, Version::new_base_version(2, 0)),
+        ] },
+        non_ascii = { "é1.2ü", &[(2, 5, Version::new_base_version(1, 2))] },
+    )]
+    fn word(input: &str, expected: &[(usize, usize, Version)]) {
+        assert_eq!(versions(Scanner::new(), input), expected);
+    }
+
+    #[yare::parameterized(
+        inside_word = { "x1.2y", &[(1, 4, Version::new_base_version(1, 2))] },
+        ip_address = { "192.168.1.1", &[(0, 9, Version::new_full_version(192, 168, 1))] },
+        leading_zero = { "01.2", &[] },
+        adjacent = { "1.2-3.4", &[(0, 3, Version::new_base_version(1, 2)), (4, 7, Version::new_base_version(3, 4))] },
+    )]
+    fn no_boundary(input: &str, expected: &[(usize, usize, Version)]) {
+        let scanner = Scanner::new().with_boundary(Boundary::None);
+
+        assert_eq!(versions(scanner, input), expected);
+    }
+
+    #[test]
+    fn custom_boundary() {
+        let scanner = Scanner::new().with_boundary(Boundary::Custom(|b| b == b'-'));
+
+        assert_eq!(
+            versions(scanner, "x1.2 a-1.3"),
+            vec![(1, 4, Version::new_base_version(1, 2))]
+        );
+    }
+
+    #[yare::parameterized(
+        base = { Variant::Base, &[(0, 3, Version::new_base_version(1, 2))] },
+        full = { Variant::Full, &[(8, 13, Version::new_full_version(1, 2, 3))] },
+    )]
+    fn variant(variant: Variant, expected: &[(usize, usize, Version)]) {
+        let scanner = Scanner::new().with_variant(variant);
+
+        assert_eq!(versions(scanner, "1.2 and 1.2.3"), expected);
+    }
+
+    #[yare::parameterized(
+        base = { Variant::Base, &[(0, 3, Version::new_base_version(1, 2)), (8, 11, Version::new_base_version(1, 2))] },
+        full = { Variant::Full, &[(8, 13, Version::new_full_version(1, 2, 3))] },
+    )]
+    fn variant_without_boundary(variant: Variant, expected: &[(usize, usize, Version)]) {
+        let scanner = Scanner::new()
+            .with_boundary(Boundary::None)
+            .with_variant(variant);
+
+        assert_eq!(versions(scanner, "1.2 and 1.2.3"), expected);
+    }
+
+    #[test]
+    fn without_v_prefix() {
+        let scanner = Scanner::new().with_v_prefix(false);
+
+        assert_eq!(
+            versions(scanner, "v1.2 (1.3)"),
+            vec![(6, 9, Version::new_base_version(1, 3))]
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        let input = b"\xff1.2\xfe";
+        let found = Scanner::new().scan(&input[..]).collect::<Vec<_>>();
+
+        assert_eq!(
+            found,
+            vec![(Span::new(1, 4), Version::new_base_version(1, 2))]
+        );
+    }
+}
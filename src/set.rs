@@ -0,0 +1,580 @@
+#[cfg(feature = "pubgrub")]
+mod pubgrub;
+
+use crate::{BaseVersion, Comparator, FullVersion, Op, Version, VersionReq};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A set of versions, which consists of disjoint intervals of [`FullVersion`]s.
+///
+/// Sets may be combined with [`VersionSet::union`], [`VersionSet::intersection`] and
+/// [`VersionSet::complement`], for example to compute the versions which are supported by each
+/// of a number of crates, but which are not affected by a known bug.
+///
+/// The intervals are kept sorted, and intervals which overlap or are adjacent are merged, so two
+/// sets are equal if, and only if, they contain the same versions.
+///
+/// # Two component versions
+///
+/// A two component [`BaseVersion`] `major.minor` is converted to the set of each three component
+/// version `major.minor.patch`, i.e. to `>=major.minor.0, <major.(minor+1).0`. Likewise, a
+/// [`VersionReq`] is converted to the set of the three component versions which it matches.
+///
+/// # Display
+///
+/// A set is displayed in a requirement-like syntax, where each interval is displayed like a
+/// [`VersionReq`], and the intervals are separated by `||`. The empty set is displayed as
+/// `<0.0.0`, and the set of all versions as `*`.
+///
+/// # Example
+///
+/// ```
+/// use version_number::{FullVersion, VersionReq, VersionSet};
+///
+/// let a = VersionSet::from(&VersionReq::parse(">=1.56").unwrap());
+/// let b = VersionSet::from(&VersionReq::parse("<1.70").unwrap());
+/// let bug = VersionSet::from(FullVersion::new(1, 64, 0));
+///
+/// let supported = a.intersection(&b).intersection(&bug.complement());
+///
+/// assert!(supported.contains(&FullVersion::new(1, 63, 5)));
+/// assert!(!supported.contains(&FullVersion::new(1, 64, 0)));
+/// assert_eq!(supported.to_string(), ">=1.56.0, <1.64.0 || >=1.64.1, <1.70.0");
+/// ```
+///
+/// [`BaseVersion`]: crate::BaseVersion
+/// [`VersionReq`]: crate::VersionReq
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct VersionSet {
+    // Sorted, non-empty, and neither overlapping nor adjacent.
+    intervals: Vec<Interval>,
+}
+
+impl VersionSet {
+    /// The set which contains no versions.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// The set which contains every version.
+    pub fn full() -> Self {
+        Self::at_least(MIN)
+    }
+
+    /// The set of the versions which are greater than or equal to `start`.
+    pub fn at_least(start: FullVersion) -> Self {
+        Self::from_intervals(vec![Interval { start, end: None }])
+    }
+
+    /// The set of the versions which are less than `end`.
+    pub fn less_than(end: FullVersion) -> Self {
+        Self::between(MIN, end)
+    }
+
+    /// The set of the versions which are greater than or equal to `start`, and less than `end`.
+    ///
+    /// The set is empty if `start` is not less than `end`.
+    pub fn between(start: FullVersion, end: FullVersion) -> Self {
+        Self::from_intervals(vec![Interval {
+            start,
+            end: Some(end),
+        }])
+    }
+
+    /// Whether the set contains no versions.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Whether the set contains the given `version`.
+    pub fn contains(&self, version: &FullVersion) -> bool {
+        let after = self
+            .intervals
+            .partition_point(|interval| interval.start <= *version);
+
+        after > 0 && self.intervals[after - 1].contains(version)
+    }
+
+    /// The set of the versions which are contained by `self`, by `other`, or by both.
+    pub fn union(&self, other: &Self) -> Self {
+        let intervals = self.intervals.iter().chain(&other.intervals).copied();
+
+        Self::from_intervals(intervals.collect())
+    }
+
+    /// The set of the versions which are contained by both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut lhs = self.intervals.iter().peekable();
+        let mut rhs = other.intervals.iter().peekable();
+
+        while let (Some(a), Some(b)) = (lhs.peek(), rhs.peek()) {
+            let interval = Interval {
+                start: a.start.max(b.start),
+                end: min_end(a.end, b.end),
+            };
+
+            if !interval.is_empty() {
+                intervals.push(interval);
+            }
+
+            // Advance past the interval which ends first, since it can't overlap any other
+            // interval of the other set.
+            if cmp_end(a.end, b.end) == Ordering::Less {
+                lhs.next();
+            } else {
+                rhs.next();
+            }
+        }
+
+        Self { intervals }
+    }
+
+    /// The set of the versions which are not contained by `self`.
+    pub fn complement(&self) -> Self {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        let mut start = Some(MIN);
+
+        for interval in &self.intervals {
+            let gap_start = match start {
+                Some(gap_start) => gap_start,
+                None => break,
+            };
+
+            if gap_start < interval.start {
+                intervals.push(Interval {
+                    start: gap_start,
+                    end: Some(interval.start),
+                });
+            }
+
+            start = interval.end;
+        }
+
+        if let Some(start) = start {
+            intervals.push(Interval { start, end: None });
+        }
+
+        Self { intervals }
+    }
+
+    // Sorts the given intervals, merges those which overlap or are adjacent, and drops those
+    // which are empty.
+    fn from_intervals(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by_key(|interval| interval.start);
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.end.map_or(true, |end| end >= interval.start) => {
+                    last.end = max_end(last.end, interval.end);
+                }
+                _ => merged.push(interval),
+            }
+        }
+
+        Self { intervals: merged }
+    }
+}
+
+impl From<FullVersion> for VersionSet {
+    /// The set which only contains the given version.
+    fn from(version: FullVersion) -> Self {
+        Self::from_intervals(vec![Interval {
+            start: version,
+            end: successor(version),
+        }])
+    }
+}
+
+impl From<BaseVersion> for VersionSet {
+    /// The set of each three component version with the `major` and `minor` components of the
+    /// given version.
+    fn from(version: BaseVersion) -> Self {
+        let last = FullVersion::new(version.major, version.minor, u64::MAX);
+
+        Self::from_intervals(vec![Interval {
+            start: version.to_full_version_lossy(),
+            end: successor(last),
+        }])
+    }
+}
+
+impl From<Version> for VersionSet {
+    fn from(version: Version) -> Self {
+        match version {
+            Version::Base(base) => Self::from(base),
+            Version::Full(full) => Self::from(full),
+        }
+    }
+}
+
+impl From<&VersionReq> for VersionSet {
+    /// The set of the three component versions which match the given requirement.
+    fn from(requirement: &VersionReq) -> Self {
+        requirement
+            .comparators
+            .iter()
+            .fold(Self::full(), |set, comparator| {
+                set.intersection(&Self::from_intervals(vec![Interval::from(comparator)]))
+            })
+    }
+}
+
+impl fmt::Display for VersionSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut intervals = self.intervals.iter();
+
+        match intervals.next() {
+            Some(first) => fmt::Display::fmt(first, f)?,
+            None => return write!(f, "<{}", MIN),
+        }
+
+        for interval in intervals {
+            write!(f, " || {}", interval)?;
+        }
+
+        Ok(())
+    }
+}
+
+// The smallest version.
+const MIN: FullVersion = FullVersion::new(0, 0, 0);
+
+// The versions from `start` up to, but excluding, `end`, or without upper bound if `end` is
+// `None`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+struct Interval {
+    start: FullVersion,
+    end: Option<FullVersion>,
+}
+
+impl Interval {
+    fn is_empty(&self) -> bool {
+        self.end.map_or(false, |end| end <= self.start)
+    }
+
+    fn contains(&self, version: &FullVersion) -> bool {
+        self.start <= *version && !self.ends_before(version)
+    }
+
+    // Whether the interval ends at or before the given version, i.e. doesn't contain it, nor
+    // any greater version.
+    fn ends_before(&self, version: &FullVersion) -> bool {
+        self.end.map_or(false, |end| end <= *version)
+    }
+}
+
+impl From<&Comparator> for Interval {
+    // The interval of the versions which match the comparator. The bounds are derived as shown
+    // in the table of `Op`.
+    fn from(comparator: &Comparator) -> Self {
+        let Comparator {
+            op,
+            major,
+            minor,
+            patch,
+        } = *comparator;
+
+        let lower = FullVersion::new(major, minor.unwrap_or(0), patch.unwrap_or(0));
+        // The last version which equals each of the specified components.
+        let last = FullVersion::new(major, minor.unwrap_or(u64::MAX), patch.unwrap_or(u64::MAX));
+        // The first version with a greater left-most component than is allowed by `^` and `~`.
+        let next_major = successor(FullVersion::new(major, u64::MAX, u64::MAX));
+        let next_minor = successor(FullVersion::new(major, minor.unwrap_or(0), u64::MAX));
+
+        let (start, end) = match op {
+            Op::Exact | Op::Wildcard => (Some(lower), successor(last)),
+            Op::Greater => (successor(last), None),
+            Op::GreaterEq => (Some(lower), None),
+            Op::Less => (Some(MIN), Some(lower)),
+            Op::LessEq => (Some(MIN), successor(last)),
+            Op::Tilde if minor.is_some() => (Some(lower), next_minor),
+            Op::Tilde => (Some(lower), next_major),
+            Op::Caret => match (major, minor, patch) {
+                (_, None, _) => (Some(lower), next_major),
+                (0, Some(0), Some(_)) => (Some(lower), successor(lower)),
+                (0, Some(_), _) => (Some(lower), next_minor),
+                (_, Some(_), _) => (Some(lower), next_major),
+            },
+        };
+
+        match start {
+            Some(start) => Interval { start, end },
+            // No version is greater than the largest version.
+            None => Interval {
+                start: MIN,
+                end: Some(MIN),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.start, self.end) {
+            (MIN, None) => f.write_str("*"),
+            (start, None) => write!(f, ">={}", start),
+            (start, Some(end)) if successor(start) == Some(end) => write!(f, "={}", start),
+            (MIN, Some(end)) => write!(f, "<{}", end),
+            (start, Some(end)) => write!(f, ">={}, <{}", start, end),
+        }
+    }
+}
+
+// The smallest version which is greater than the given version, if any.
+fn successor(version: FullVersion) -> Option<FullVersion> {
+    let FullVersion {
+        major,
+        minor,
+        patch,
+    } = version;
+
+    if let Some(patch) = patch.checked_add(1) {
+        Some(FullVersion::new(major, minor, patch))
+    } else if let Some(minor) = minor.checked_add(1) {
+        Some(FullVersion::new(major, minor, 0))
+    } else {
+        major
+            .checked_add(1)
+            .map(|major| FullVersion::new(major, 0, 0))
+    }
+}
+
+// Compares the ends of two intervals, where `None` is greater than any version.
+fn cmp_end(lhs: Option<FullVersion>, rhs: Option<FullVersion>) -> Ordering {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+        (lhs, rhs) => lhs.is_some().cmp(&rhs.is_some()).reverse(),
+    }
+}
+
+fn min_end(lhs: Option<FullVersion>, rhs: Option<FullVersion>) -> Option<FullVersion> {
+    match cmp_end(lhs, rhs) {
+        Ordering::Greater => rhs,
+        _ => lhs,
+    }
+}
+
+fn max_end(lhs: Option<FullVersion>, rhs: Option<FullVersion>) -> Option<FullVersion> {
+    match cmp_end(lhs, rhs) {
+        Ordering::Less => rhs,
+        _ => lhs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(requirement: &str) -> VersionSet {
+        VersionSet::from(&VersionReq::parse(requirement).unwrap())
+    }
+
+    fn parse_full(version: &str) -> FullVersion {
+        FullVersion::parse(version).unwrap()
+    }
+
+    #[yare::parameterized(
+        star = { "*", "*" },
+        exact = { "=1.2.3", "=1.2.3" },
+        exact_minor = { "=1.2", ">=1.2.0, <1.3.0" },
+        greater = { ">1.2.3", ">=1.2.4" },
+        greater_minor = { ">1.2", ">=1.3.0" },
+        greater_max = { ">18446744073709551615", "<0.0.0" },
+        greater_eq = { ">=1.56", ">=1.56.0" },
+        less = { "<1.70", "<1.70.0" },
+        less_zero = { "<0.0.0", "<0.0.0" },
+        less_eq = { "<=1.2", "<1.3.0" },
+        tilde = { "~1.2.3", ">=1.2.3, <1.3.0" },
+        tilde_major = { "~1", ">=1.0.0, <2.0.0" },
+        caret = { "^1.2.3", ">=1.2.3, <2.0.0" },
+        caret_zero_minor = { "^0.2.3", ">=0.2.3, <0.3.0" },
+        caret_zero_patch = { "^0.0.3", "=0.0.3" },
+        caret_zero_base = { "^0.0", "<0.1.0" },
+        wildcard = { "1.2.*", ">=1.2.0, <1.3.0" },
+        range = { ">=1.56, <1.70", ">=1.56.0, <1.70.0" },
+        disjoint = { ">=1.70, <1.56", "<0.0.0" },
+        max = { "=18446744073709551615.18446744073709551615.18446744073709551615", ">=18446744073709551615.18446744073709551615.18446744073709551615" },
+    )]
+    fn display(requirement: &str, expected: &str) {
+        assert_eq!(set(requirement).to_string(), expected);
+    }
+
+    #[yare::parameterized(
+        star = { "*" },
+        exact = { "=1.2.3" },
+        exact_minor = { "=1.2" },
+        exact_major = { "=1" },
+        greater = { ">1.2.3" },
+        greater_minor = { ">1.2" },
+        greater_major = { ">1" },
+        greater_eq = { ">=1.2.3" },
+        less = { "<1.2.3" },
+        less_minor = { "<1.2" },
+        less_eq = { "<=1.2.3" },
+        less_eq_minor = { "<=1.2" },
+        less_eq_major = { "<=1" },
+        tilde = { "~1.2.3" },
+        tilde_minor = { "~1.2" },
+        tilde_major = { "~1" },
+        caret = { "^1.2.3" },
+        caret_minor = { "^1.2" },
+        caret_major = { "^1" },
+        caret_zero = { "^0" },
+        caret_zero_minor = { "^0.2.3" },
+        caret_zero_minor_base = { "^0.2" },
+        caret_zero_patch = { "^0.0.3" },
+        caret_zero_base = { "^0.0" },
+        wildcard_minor = { "1.*" },
+        wildcard_patch = { "1.2.*" },
+        range = { ">=1.1, <2.1.1" },
+    )]
+    fn same_as_requirement(requirement: &str) {
+        let req = VersionReq::parse(requirement).unwrap();
+        let set = VersionSet::from(&req);
+
+        for major in 0..4 {
+            for minor in 0..4 {
+                for patch in 0..5 {
+                    let version = FullVersion::new(major, minor, patch);
+
+                    assert_eq!(
+                        set.contains(&version),
+                        req.matches(&Version::Full(version)),
+                        "{} in {}",
+                        version,
+                        requirement
+                    );
+                }
+            }
+        }
+    }
+
+    #[yare::parameterized(
+        overlapping = { ">=1.2, <1.5", ">=1.4, <1.8", ">=1.2.0, <1.8.0" },
+        adjacent = { ">=1.2, <1.5", ">=1.5, <1.8", ">=1.2.0, <1.8.0" },
+        disjoint = { ">=1.5, <1.8", "<1.2", "<1.2.0 || >=1.5.0, <1.8.0" },
+        subset = { "*", "=1.2.3", "*" },
+        empty = { "<0.0.0", "=1.2.3", "=1.2.3" },
+    )]
+    fn union(lhs: &str, rhs: &str, expected: &str) {
+        assert_eq!(set(lhs).union(&set(rhs)).to_string(), expected);
+        assert_eq!(set(rhs).union(&set(lhs)).to_string(), expected);
+    }
+
+    #[yare::parameterized(
+        overlapping = { ">=1.2, <1.5", ">=1.4, <1.8", ">=1.4.0, <1.5.0" },
+        adjacent = { ">=1.2, <1.5", ">=1.5, <1.8", "<0.0.0" },
+        subset = { "*", "=1.2.3", "=1.2.3" },
+        empty = { "<0.0.0", "*", "<0.0.0" },
+        many = { "<1.2 || >=1.4, <1.6 || >=1.8", ">=1.1, <1.9", ">=1.1.0, <1.2.0 || >=1.4.0, <1.6.0 || >=1.8.0, <1.9.0" },
+    )]
+    fn intersection(lhs: &str, rhs: &str, expected: &str) {
+        let lhs = union_of(lhs);
+        let rhs = union_of(rhs);
+
+        assert_eq!(lhs.intersection(&rhs).to_string(), expected);
+        assert_eq!(rhs.intersection(&lhs).to_string(), expected);
+    }
+
+    // Parses a set in its display syntax.
+    fn union_of(input: &str) -> VersionSet {
+        input
+            .split("||")
+            .map(set)
+            .fold(VersionSet::empty(), |acc, set| acc.union(&set))
+    }
+
+    #[yare::parameterized(
+        full = { "*", "<0.0.0" },
+        empty = { "<0.0.0", "*" },
+        at_least = { ">=1.56", "<1.56.0" },
+        less_than = { "<1.56", ">=1.56.0" },
+        exact = { "=1.2.3", "<1.2.3 || >=1.2.4" },
+        many = { "<1.2 || >=1.4, <1.6", ">=1.2.0, <1.4.0 || >=1.6.0" },
+    )]
+    fn complement(input: &str, expected: &str) {
+        let complement = union_of(input).complement();
+
+        assert_eq!(complement.to_string(), expected);
+        assert_eq!(complement.complement(), union_of(input));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let set = union_of("<1.2 || =1.3.0 || >=1.4, <1.6 || >=2");
+
+        assert_eq!(
+            set.to_string(),
+            "<1.2.0 || =1.3.0 || >=1.4.0, <1.6.0 || >=2.0.0"
+        );
+        assert_eq!(union_of(&set.to_string()), set);
+    }
+
+    #[test]
+    fn from_base_version() {
+        let set = VersionSet::from(BaseVersion::new(1, 2));
+
+        assert_eq!(set.to_string(), ">=1.2.0, <1.3.0");
+        assert!(set.contains(&parse_full("1.2.0")));
+        assert!(set.contains(&parse_full("1.2.18446744073709551615")));
+        assert!(!set.contains(&parse_full("1.3.0")));
+    }
+
+    #[test]
+    fn from_base_version_max() {
+        let set = VersionSet::from(BaseVersion::new(u64::MAX, u64::MAX));
+
+        assert_eq!(
+            set.to_string(),
+            ">=18446744073709551615.18446744073709551615.0"
+        );
+    }
+
+    #[yare::parameterized(
+        base = { Version::new_base_version(1, 2), ">=1.2.0, <1.3.0" },
+        full = { Version::new_full_version(1, 2, 3), "=1.2.3" },
+        patch_max = { Version::new_full_version(1, 2, u64::MAX), "=1.2.18446744073709551615" },
+    )]
+    fn from_version(version: Version, expected: &str) {
+        assert_eq!(VersionSet::from(version).to_string(), expected);
+    }
+
+    #[test]
+    fn contains() {
+        let set = union_of("<1.2 || =1.3.0 || >=1.4, <1.6");
+
+        assert!(set.contains(&parse_full("0.0.0")));
+        assert!(set.contains(&parse_full("1.1.99")));
+        assert!(!set.contains(&parse_full("1.2.0")));
+        assert!(set.contains(&parse_full("1.3.0")));
+        assert!(!set.contains(&parse_full("1.3.1")));
+        assert!(set.contains(&parse_full("1.5.99")));
+        assert!(!set.contains(&parse_full("1.6.0")));
+        assert!(!VersionSet::empty().contains(&parse_full("0.0.0")));
+        assert!(VersionSet::full().contains(&parse_full("0.0.0")));
+    }
+
+    #[yare::parameterized(
+        empty = { VersionSet::empty(), true },
+        full = { VersionSet::full(), false },
+        reversed = { VersionSet::between(parse_full("1.2.0"), parse_full("1.1.0")), true },
+        equal = { VersionSet::between(parse_full("1.2.0"), parse_full("1.2.0")), true },
+        between = { VersionSet::between(parse_full("1.1.0"), parse_full("1.2.0")), false },
+    )]
+    fn is_empty(set: VersionSet, expected: bool) {
+        assert_eq!(set.is_empty(), expected);
+    }
+
+    #[test]
+    fn equality_is_set_equality() {
+        let lhs = VersionSet::between(parse_full("1.0.0"), parse_full("1.5.0")).union(
+            &VersionSet::between(parse_full("1.5.0"), parse_full("2.0.0")),
+        );
+
+        assert_eq!(lhs, set("^1"));
+    }
+}
@@ -0,0 +1,269 @@
+//! Implementations of the traits of the `pubgrub` crate, so the version types of this crate may
+//! be used by its dependency resolver.
+//!
+//! A [`FullVersion`] may be used as the version type of a `pubgrub::DependencyProvider`, with
+//! either a [`VersionSet`] or a `pubgrub::Ranges<FullVersion>` as its set of versions. A
+//! [`BaseVersion`] is converted to the set of each three component version with its `major` and
+//! `minor` components, e.g. `1.70` to `>=1.70.0, <1.71.0`.
+//!
+//! Requires the `pubgrub` feature to be enabled.
+
+use super::{successor, MIN};
+use crate::{BaseVersion, FullVersion, Version, VersionSet};
+use ::pubgrub::Ranges;
+
+impl ::pubgrub::VersionSet for VersionSet {
+    type V = FullVersion;
+
+    fn empty() -> Self {
+        VersionSet::empty()
+    }
+
+    fn singleton(version: FullVersion) -> Self {
+        VersionSet::from(version)
+    }
+
+    fn complement(&self) -> Self {
+        VersionSet::complement(self)
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        VersionSet::intersection(self, other)
+    }
+
+    fn contains(&self, version: &FullVersion) -> bool {
+        VersionSet::contains(self, version)
+    }
+
+    fn full() -> Self {
+        VersionSet::full()
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        VersionSet::union(self, other)
+    }
+}
+
+impl From<&VersionSet> for Ranges<FullVersion> {
+    /// Convert the given [`VersionSet`] to the `pubgrub::Ranges` which contains the same
+    /// versions.
+    fn from(set: &VersionSet) -> Self {
+        set.intervals
+            .iter()
+            .fold(Ranges::empty(), |ranges, interval| {
+                // Like the `Display` of an interval, use the simplest of the equivalent ranges.
+                let range = match (interval.start, interval.end) {
+                    (MIN, None) => Ranges::full(),
+                    (start, None) => Ranges::higher_than(start),
+                    (start, Some(end)) if successor(start) == Some(end) => Ranges::singleton(start),
+                    (MIN, Some(end)) => Ranges::strictly_lower_than(end),
+                    (start, Some(end)) => Ranges::between(start, end),
+                };
+
+                ranges.union(&range)
+            })
+    }
+}
+
+impl From<BaseVersion> for Ranges<FullVersion> {
+    /// The `pubgrub::Ranges` of each three component version with the `major` and `minor`
+    /// components of the given version.
+    fn from(version: BaseVersion) -> Self {
+        Ranges::from(&VersionSet::from(version))
+    }
+}
+
+impl From<Version> for Ranges<FullVersion> {
+    fn from(version: Version) -> Self {
+        Ranges::from(&VersionSet::from(version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BaseVersion, FullVersion, Version, VersionReq, VersionSet};
+    use pubgrub::{
+        resolve, Dependencies, DependencyProvider, OfflineDependencyProvider,
+        PackageResolutionStatistics, PubGrubError, Ranges, SelectedDependencies,
+    };
+    use std::cmp::Reverse;
+    use std::collections::BTreeMap;
+    use std::convert::Infallible;
+
+    type Package = &'static str;
+
+    // An in-memory index, which maps each version of each package to its dependencies.
+    #[derive(Default)]
+    struct Index {
+        packages: BTreeMap<Package, BTreeMap<FullVersion, Vec<(Package, VersionSet)>>>,
+    }
+
+    impl Index {
+        fn add(&mut self, package: Package, version: &str, dependencies: &[(Package, VersionSet)]) {
+            self.packages
+                .entry(package)
+                .or_default()
+                .insert(FullVersion::parse(version).unwrap(), dependencies.to_vec());
+        }
+
+        // The versions of the package which are contained by the given set, from new to old.
+        fn candidates<'a>(
+            &'a self,
+            package: &Package,
+            set: &'a VersionSet,
+        ) -> impl Iterator<Item = &'a FullVersion> {
+            self.packages
+                .get(package)
+                .into_iter()
+                .flat_map(|versions| versions.keys().rev())
+                .filter(move |version| set.contains(version))
+        }
+    }
+
+    impl DependencyProvider for Index {
+        type P = Package;
+        type V = FullVersion;
+        type VS = VersionSet;
+        type M = String;
+        type Err = Infallible;
+        // Packages with fewer candidates are decided first.
+        type Priority = Reverse<usize>;
+
+        fn prioritize(
+            &self,
+            package: &Package,
+            set: &VersionSet,
+            _: &PackageResolutionStatistics,
+        ) -> Self::Priority {
+            Reverse(self.candidates(package, set).count())
+        }
+
+        fn choose_version(
+            &self,
+            package: &Package,
+            set: &VersionSet,
+        ) -> Result<Option<FullVersion>, Infallible> {
+            Ok(self.candidates(package, set).next().copied())
+        }
+
+        fn get_dependencies(
+            &self,
+            package: &Package,
+            version: &FullVersion,
+        ) -> Result<Dependencies<Package, VersionSet, String>, Infallible> {
+            let dependencies = self
+                .packages
+                .get(package)
+                .and_then(|versions| versions.get(version));
+
+            Ok(match dependencies {
+                Some(dependencies) => {
+                    Dependencies::Available(dependencies.iter().cloned().collect())
+                }
+                None => Dependencies::Unavailable(format!("{} {} is unknown", package, version)),
+            })
+        }
+    }
+
+    fn req(requirement: &str) -> VersionSet {
+        VersionSet::from(&VersionReq::parse(requirement).unwrap())
+    }
+
+    fn base_set(major: u64, minor: u64) -> VersionSet {
+        VersionSet::from(BaseVersion::new(major, minor))
+    }
+
+    fn toolchain_index(rustc: VersionSet) -> Index {
+        let mut index = Index::default();
+
+        index.add(
+            "toolchain",
+            "1.0.0",
+            &[("rustc", rustc), ("cargo", base_set(1, 72))],
+        );
+
+        for version in ["1.69.0", "1.70.0", "1.72.0", "1.72.1", "1.73.0"] {
+            index.add("rustc", version, &[]);
+        }
+
+        index.add("cargo", "1.71.0", &[("rustc", req("~1.71"))]);
+        index.add("cargo", "1.72.0", &[("rustc", req("=1.72.0"))]);
+        index.add("cargo", "1.72.2", &[("rustc", req("~1.72"))]);
+        index.add("cargo", "1.73.0", &[("rustc", req("~1.73"))]);
+
+        index
+    }
+
+    #[test]
+    fn resolve_index() {
+        let index = toolchain_index(req("^1.70"));
+        let solution: SelectedDependencies<Index> =
+            resolve(&index, "toolchain", FullVersion::new(1, 0, 0)).unwrap();
+
+        assert_eq!(solution.len(), 3);
+        assert_eq!(solution["toolchain"], FullVersion::new(1, 0, 0));
+        assert_eq!(solution["cargo"], FullVersion::new(1, 72, 2));
+        assert_eq!(solution["rustc"], FullVersion::new(1, 72, 1));
+    }
+
+    #[test]
+    fn resolve_index_without_solution() {
+        let index = toolchain_index(req("<1.70"));
+        let err = resolve(&index, "toolchain", FullVersion::new(1, 0, 0)).unwrap_err();
+
+        assert!(matches!(err, PubGrubError::NoSolution(_)));
+    }
+
+    #[test]
+    fn resolve_offline_with_ranges() {
+        let mut provider = OfflineDependencyProvider::<Package, Ranges<FullVersion>>::new();
+
+        provider.add_dependencies(
+            "toolchain",
+            FullVersion::new(1, 0, 0),
+            [("rustc", Ranges::from(BaseVersion::new(1, 70)))],
+        );
+        provider.add_dependencies("rustc", FullVersion::new(1, 70, 1), []);
+        provider.add_dependencies("rustc", FullVersion::new(1, 71, 0), []);
+
+        let solution = resolve(&provider, "toolchain", FullVersion::new(1, 0, 0)).unwrap();
+
+        assert_eq!(solution["rustc"], FullVersion::new(1, 70, 1));
+    }
+
+    #[yare::parameterized(
+        empty = { VersionSet::empty(), Ranges::empty() },
+        full = { VersionSet::full(), Ranges::full() },
+        base = { base_set(1, 2), Ranges::between(FullVersion::new(1, 2, 0), FullVersion::new(1, 3, 0)) },
+        many = {
+            req("<1.2").union(&req(">=1.4")),
+            Ranges::strictly_lower_than(FullVersion::new(1, 2, 0)).union(&Ranges::higher_than(FullVersion::new(1, 4, 0)))
+        },
+    )]
+    fn to_ranges(set: VersionSet, expected: Ranges<FullVersion>) {
+        assert_eq!(Ranges::from(&set), expected);
+    }
+
+    #[yare::parameterized(
+        base = { Version::new_base_version(1, 2), Ranges::between(FullVersion::new(1, 2, 0), FullVersion::new(1, 3, 0)) },
+        full = { Version::new_full_version(1, 2, 3), Ranges::singleton(FullVersion::new(1, 2, 3)) },
+    )]
+    fn version_to_ranges(version: Version, expected: Ranges<FullVersion>) {
+        assert_eq!(Ranges::from(version), expected);
+    }
+
+    #[test]
+    fn version_set_trait() {
+        use pubgrub::VersionSet as _;
+
+        let singleton = VersionSet::singleton(FullVersion::new(1, 2, 3));
+
+        assert_eq!(singleton, req("=1.2.3"));
+        assert!(singleton.subset_of(&base_set(1, 2)));
+        assert!(singleton.is_disjoint(&base_set(1, 3)));
+        assert_eq!(
+            <VersionSet as pubgrub::VersionSet>::empty().complement(),
+            <VersionSet as pubgrub::VersionSet>::full()
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Helpers to describe TOML values in error messages.
+
+/// The indefinite article for the given TOML type name, as returned by `toml::Value::type_str`,
+/// e.g. "an" for "integer".
+pub(crate) fn indefinite_article(type_str: &str) -> &'static str {
+    match type_str.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => "an",
+        _ => "a",
+    }
+}
@@ -0,0 +1,500 @@
+//! Parse Rust toolchain names, and the `rust-toolchain` and `rust-toolchain.toml` files which
+//! pin them.
+//!
+//! A toolchain name, as understood by rustup, consists of a channel, an optional date for the
+//! `beta` and `nightly` channels, and an optional host triple, e.g. `stable`, `1.70`,
+//! `nightly-2023-05-01` or `1.70.0-x86_64-unknown-linux-gnu`.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::toolchain::{Channel, Date, Toolchain};
+//!
+//! let toolchain = Toolchain::parse("nightly-2023-05-01-x86_64-unknown-linux-gnu").unwrap();
+//!
+//! assert_eq!(toolchain.channel, Channel::Nightly(Some(Date::new(2023, 5, 1))));
+//! assert_eq!(toolchain.host.as_deref(), Some("x86_64-unknown-linux-gnu"));
+//! assert_eq!(toolchain.to_string(), "nightly-2023-05-01-x86_64-unknown-linux-gnu");
+//! ```
+
+use crate::parsers::original::OriginalParser;
+use crate::{ParserError, Version, VersionParser};
+use std::fmt;
+use std::str::FromStr;
+
+/// A Rust toolchain, as named by rustup.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Toolchain {
+    /// The release channel of the toolchain.
+    pub channel: Channel,
+    /// The host triple of the toolchain, e.g. `x86_64-unknown-linux-gnu`, if specified.
+    pub host: Option<String>,
+}
+
+impl Toolchain {
+    /// Create a new toolchain for the given channel, without a host triple.
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            channel,
+            host: None,
+        }
+    }
+
+    /// Parse a toolchain name, such as `stable`, `1.70.0` or `nightly-2023-05-01`.
+    pub fn parse(input: &str) -> Result<Self, ToolchainError> {
+        if input.is_empty() {
+            return Err(ToolchainError::Empty);
+        }
+
+        let (channel, rest) = parse_channel(input)?;
+
+        let host = match rest {
+            "" => None,
+            _ => match rest.strip_prefix('-') {
+                Some("") => return Err(ToolchainError::EmptyHost),
+                Some(host) => Some(host.to_string()),
+                None => {
+                    return Err(ToolchainError::UnexpectedInput {
+                        input: rest.to_string(),
+                    })
+                }
+            },
+        };
+
+        Ok(Self { channel, host })
+    }
+
+    /// Parse the contents of a legacy `rust-toolchain` file, which consists of a single toolchain
+    /// name.
+    ///
+    /// Leading and trailing whitespace is ignored.
+    pub fn from_legacy_str(contents: &str) -> Result<Self, ToolchainError> {
+        let contents = contents.trim();
+
+        if contents.lines().count() > 1 {
+            return Err(ToolchainError::MultipleLines);
+        }
+
+        Self::parse(contents)
+    }
+
+    /// Parse the contents of a `rust-toolchain.toml` file, by its `toolchain.channel` key.
+    ///
+    /// Other keys, such as `components` and `targets`, are not read.
+    ///
+    /// Requires the `toml` feature to be enabled.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(contents: &str) -> Result<Self, ToolchainError> {
+        let document: toml::Table = toml::from_str(contents).map_err(ToolchainError::Toml)?;
+
+        let channel = document
+            .get("toolchain")
+            .and_then(|toolchain| toolchain.get("channel"))
+            .ok_or(ToolchainError::MissingChannel)?;
+
+        match channel {
+            toml::Value::String(channel) => Self::parse(channel),
+            other => Err(ToolchainError::ChannelNotAString {
+                found: other.type_str(),
+            }),
+        }
+    }
+}
+
+impl FromStr for Toolchain {
+    type Err = ToolchainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Toolchain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.host {
+            Some(host) => write!(f, "{}-{}", self.channel, host),
+            None => fmt::Display::fmt(&self.channel, f),
+        }
+    }
+}
+
+/// The release channel of a Rust toolchain.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Channel {
+    /// The latest stable release.
+    Stable,
+    /// The beta release, optionally of a specific date.
+    Beta(Option<Date>),
+    /// The nightly release, optionally of a specific date.
+    Nightly(Option<Date>),
+    /// A specific numbered release, e.g. `1.70` or `1.70.0`.
+    Version(Version),
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stable => f.write_str("stable"),
+            Self::Beta(None) => f.write_str("beta"),
+            Self::Beta(Some(date)) => write!(f, "beta-{}", date),
+            Self::Nightly(None) => f.write_str("nightly"),
+            Self::Nightly(Some(date)) => write!(f, "nightly-{}", date),
+            Self::Version(version) => fmt::Display::fmt(version, f),
+        }
+    }
+}
+
+/// The release date of a `beta` or `nightly` toolchain, of the form `YYYY-MM-DD`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date {
+    /// The year of the release.
+    pub year: u16,
+    /// The month of the release, from 1 up to and including 12.
+    pub month: u8,
+    /// The day of the month of the release, from 1 up to and including 31.
+    pub day: u8,
+}
+
+impl Date {
+    /// Create a new date.
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Parse a date of the form `YYYY-MM-DD`.
+    pub fn parse(input: &str) -> Result<Self, ToolchainError> {
+        let invalid = || ToolchainError::InvalidDate {
+            date: input.to_string(),
+        };
+
+        let bytes = input.as_bytes();
+
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(invalid());
+        }
+
+        let year = parse_digits(&input[0..4]).ok_or_else(invalid)?;
+        let month = parse_digits(&input[5..7]).ok_or_else(invalid)?;
+        let day = parse_digits(&input[8..10]).ok_or_else(invalid)?;
+
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(year, month as u8, day as u8))
+    }
+}
+
+impl FromStr for Date {
+    type Err = ToolchainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Parse the channel at the start of `input`, and return it with the remaining input.
+fn parse_channel(input: &str) -> Result<(Channel, &str), ToolchainError> {
+    let end = input.find('-').unwrap_or(input.len());
+    let (name, rest) = input.split_at(end);
+
+    match name {
+        "stable" => Ok((Channel::Stable, rest)),
+        "beta" => parse_date(rest).map(|(date, rest)| (Channel::Beta(date), rest)),
+        "nightly" => parse_date(rest).map(|(date, rest)| (Channel::Nightly(date), rest)),
+        _ if name.starts_with(|c: char| c.is_ascii_digit()) => OriginalParser
+            .parse_version(name)
+            .map(|version| (Channel::Version(version), rest))
+            .map_err(ToolchainError::Version),
+        _ => Err(ToolchainError::UnknownChannel {
+            channel: name.to_string(),
+        }),
+    }
+}
+
+/// Parse the optional `-YYYY-MM-DD` date which may follow the `beta` and `nightly` channels.
+///
+/// A date is expected when the next `-` separated part consists of four digits, which a host
+/// triple never does.
+fn parse_date(rest: &str) -> Result<(Option<Date>, &str), ToolchainError> {
+    let candidate = match rest.strip_prefix('-') {
+        Some(candidate) => candidate,
+        None => return Ok((None, rest)),
+    };
+
+    let is_year = candidate.len() >= 4
+        && candidate.as_bytes()[..4].iter().all(u8::is_ascii_digit)
+        && candidate.as_bytes().get(4).map_or(true, |&b| b == b'-');
+
+    if !is_year {
+        return Ok((None, rest));
+    }
+
+    // The end of the date may fall inside a multi-byte character, which is never a valid date.
+    let end = candidate.len().min(10);
+    let (date, rest) = match (candidate.get(..end), candidate.get(end..)) {
+        (Some(date), Some(rest)) => (date, rest),
+        _ => {
+            return Err(ToolchainError::InvalidDate {
+                date: candidate.to_string(),
+            })
+        }
+    };
+
+    Date::parse(date).map(|date| (Some(date), rest))
+}
+
+fn parse_digits(input: &str) -> Option<u16> {
+    if input.bytes().all(|b| b.is_ascii_digit()) {
+        input.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Errors which may occur when parsing a toolchain.
+#[derive(Debug, thiserror::Error)]
+pub enum ToolchainError {
+    /// The toolchain name is empty.
+    #[error("The toolchain name is empty")]
+    Empty,
+
+    /// The channel is not `stable`, `beta`, `nightly` or a version number.
+    #[error("Unknown toolchain channel '{channel}'")]
+    UnknownChannel {
+        /// The unknown channel.
+        channel: String,
+    },
+
+    /// The channel looks like a version number, but is not a valid version.
+    #[error("Invalid toolchain version: {0}")]
+    Version(#[source] ParserError),
+
+    /// The date of a `beta` or `nightly` channel is not a valid `YYYY-MM-DD` date.
+    #[error("Invalid toolchain date '{date}', expected a date of the form 'YYYY-MM-DD'")]
+    InvalidDate {
+        /// The invalid date.
+        date: String,
+    },
+
+    /// The toolchain name ends with a `-` separator, but no host triple follows it.
+    #[error("Expected a host triple after the '-' separator")]
+    EmptyHost,
+
+    /// The channel is followed by input which is not separated by a `-`.
+    #[error("Unexpected input '{input}' after the toolchain channel")]
+    UnexpectedInput {
+        /// The unexpected input.
+        input: String,
+    },
+
+    /// A legacy `rust-toolchain` file contains more than one line.
+    #[error("Expected a single toolchain name, but the file contains multiple lines")]
+    MultipleLines,
+
+    /// The `rust-toolchain.toml` file is not a valid TOML document.
+    #[cfg(feature = "toml")]
+    #[error("Unable to parse the toolchain file: {0}")]
+    Toml(#[source] toml::de::Error),
+
+    /// The `rust-toolchain.toml` file does not have a `toolchain.channel` key.
+    #[cfg(feature = "toml")]
+    #[error("The 'toolchain.channel' key is missing")]
+    MissingChannel,
+
+    /// The value of the `toolchain.channel` key is not a string.
+    #[cfg(feature = "toml")]
+    #[error(
+        "Expected the 'toolchain.channel' value to be a string, but got {} {found}",
+        crate::toml_value::indefinite_article(.found)
+    )]
+    ChannelNotAString {
+        /// The TOML type of the value found instead.
+        found: &'static str,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::NumericError;
+
+    #[yare::parameterized(
+        stable = { "stable", Channel::Stable, None },
+        beta = { "beta", Channel::Beta(None), None },
+        beta_dated = { "beta-2023-05-01", Channel::Beta(Some(Date::new(2023, 5, 1))), None },
+        nightly = { "nightly", Channel::Nightly(None), None },
+        nightly_dated = { "nightly-2023-05-01", Channel::Nightly(Some(Date::new(2023, 5, 1))), None },
+        base_version = { "1.70", Channel::Version(Version::new_base_version(1, 70)), None },
+        full_version = { "1.70.0", Channel::Version(Version::new_full_version(1, 70, 0)), None },
+        stable_host = { "stable-x86_64-unknown-linux-gnu", Channel::Stable, Some("x86_64-unknown-linux-gnu") },
+        nightly_host = { "nightly-aarch64-apple-darwin", Channel::Nightly(None), Some("aarch64-apple-darwin") },
+        nightly_dated_host = { "nightly-2023-05-01-x86_64-pc-windows-msvc", Channel::Nightly(Some(Date::new(2023, 5, 1))), Some("x86_64-pc-windows-msvc") },
+        version_host = { "1.70.0-x86_64-unknown-linux-gnu", Channel::Version(Version::new_full_version(1, 70, 0)), Some("x86_64-unknown-linux-gnu") },
+    )]
+    fn parse(input: &str, channel: Channel, host: Option<&str>) {
+        let toolchain = Toolchain::parse(input).unwrap();
+
+        assert_eq!(toolchain.channel, channel);
+        assert_eq!(toolchain.host.as_deref(), host);
+        assert_eq!(toolchain.to_string(), input);
+    }
+
+    #[yare::parameterized(
+        stable = { Toolchain::new(Channel::Stable) },
+        nightly_dated = { Toolchain::new(Channel::Nightly(Some(Date::new(2023, 1, 9)))) },
+        version = { Toolchain::new(Channel::Version(Version::new_base_version(1, 56))) },
+        host = { Toolchain { channel: Channel::Beta(None), host: Some("i686-unknown-linux-musl".to_string()) } },
+    )]
+    fn display_round_trip(toolchain: Toolchain) {
+        let parsed = toolchain.to_string().parse::<Toolchain>().unwrap();
+
+        assert_eq!(parsed, toolchain);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(matches!(Toolchain::parse(""), Err(ToolchainError::Empty)));
+    }
+
+    #[yare::parameterized(
+        unknown = { "unstable" },
+        uppercase = { "Stable" },
+        leading_dash = { "-stable" },
+    )]
+    fn unknown_channel(input: &str) {
+        assert!(matches!(
+            Toolchain::parse(input),
+            Err(ToolchainError::UnknownChannel { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_version() {
+        let err = Toolchain::parse("1.070").unwrap_err();
+
+        assert!(matches!(
+            err,
+            ToolchainError::Version(error) if *error.reason() == NumericError::LeadingZero.into()
+        ));
+    }
+
+    #[yare::parameterized(
+        month = { "nightly-2023-13-01" },
+        day = { "nightly-2023-05-32" },
+        zero_day = { "beta-2023-05-00" },
+        short = { "nightly-2023-05-1" },
+        not_digits = { "nightly-2023-0a-01" },
+        non_ascii = { "nightly-2023-05-0é" },
+        non_ascii_host = { "beta-2023-05-0é-x86_64-unknown-linux-gnu" },
+        non_ascii_short = { "nightly-2023-é" },
+    )]
+    fn invalid_date(input: &str) {
+        assert!(matches!(
+            Toolchain::parse(input),
+            Err(ToolchainError::InvalidDate { .. })
+        ));
+    }
+
+    #[yare::parameterized(
+        stable = { "stable-" },
+        version = { "1.70.0-" },
+        nightly_dated = { "nightly-2023-05-01-" },
+    )]
+    fn empty_host(input: &str) {
+        assert!(matches!(
+            Toolchain::parse(input),
+            Err(ToolchainError::EmptyHost)
+        ));
+    }
+
+    #[test]
+    fn unexpected_input() {
+        assert!(matches!(
+            Toolchain::parse("nightly-2023-05-01x"),
+            Err(ToolchainError::UnexpectedInput { input }) if input == "x"
+        ));
+    }
+
+    #[yare::parameterized(
+        plain = { "1.70.0" },
+        trailing_newline = { "1.70.0\n" },
+        surrounding_whitespace = { "  1.70.0 \r\n" },
+    )]
+    fn legacy(contents: &str) {
+        let toolchain = Toolchain::from_legacy_str(contents).unwrap();
+
+        assert_eq!(
+            toolchain,
+            Toolchain::new(Channel::Version(Version::new_full_version(1, 70, 0)))
+        );
+    }
+
+    #[test]
+    fn legacy_multiple_lines() {
+        assert!(matches!(
+            Toolchain::from_legacy_str("stable\nbeta\n"),
+            Err(ToolchainError::MultipleLines)
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    mod toml_file {
+        use super::*;
+
+        #[yare::parameterized(
+            version = { "[toolchain]\nchannel = \"1.70\"", Channel::Version(Version::new_base_version(1, 70)) },
+            nightly = { "[toolchain]\nchannel = \"nightly-2023-05-01\"\ncomponents = [\"rustfmt\"]", Channel::Nightly(Some(Date::new(2023, 5, 1))) },
+            inline_table = { "toolchain = { channel = \"stable\", targets = [\"wasm32-unknown-unknown\"] }", Channel::Stable },
+        )]
+        fn parse(contents: &str, channel: Channel) {
+            let toolchain = Toolchain::from_toml_str(contents).unwrap();
+
+            assert_eq!(toolchain, Toolchain::new(channel));
+        }
+
+        #[yare::parameterized(
+            no_toolchain = { "" },
+            path_instead = { "[toolchain]\npath = \"/opt/rust\"" },
+        )]
+        fn missing_channel(contents: &str) {
+            assert!(matches!(
+                Toolchain::from_toml_str(contents),
+                Err(ToolchainError::MissingChannel)
+            ));
+        }
+
+        #[test]
+        fn channel_not_a_string() {
+            assert!(matches!(
+                Toolchain::from_toml_str("[toolchain]\nchannel = 1.70"),
+                Err(ToolchainError::ChannelNotAString { found: "float" })
+            ));
+        }
+
+        #[yare::parameterized(
+            integer = { "[toolchain]\nchannel = 1", "but got an integer" },
+            array = { "[toolchain]\nchannel = [\"stable\"]", "but got an array" },
+            float = { "[toolchain]\nchannel = 1.70", "but got a float" },
+        )]
+        fn channel_not_a_string_message(contents: &str, expected: &str) {
+            let err = Toolchain::from_toml_str(contents).unwrap_err();
+
+            assert!(err.to_string().ends_with(expected));
+        }
+
+        #[test]
+        fn invalid_toml() {
+            assert!(matches!(
+                Toolchain::from_toml_str("[toolchain"),
+                Err(ToolchainError::Toml(_))
+            ));
+        }
+    }
+}
@@ -1,5 +1,24 @@
 mod base;
+mod bump;
+mod components;
 mod full;
+#[cfg(feature = "std")]
+mod labeled;
+mod packed;
 
+#[cfg(feature = "semver")]
+mod semver;
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "semver")]
+pub use self::semver::SemverConversionError;
 pub use base::BaseVersion;
+pub use bump::{BumpError, BumpMode, Component};
+pub use components::ComponentVersion;
 pub use full::FullVersion;
+#[cfg(feature = "std")]
+pub use labeled::{BuildMetadata, LabeledVersion, Prerelease};
+pub use packed::{PackError, PackedBaseVersion, PackedFullVersion};
+
+pub(crate) use bump::increment;
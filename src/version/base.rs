@@ -1,6 +1,7 @@
-use crate::parsers::modular;
-use crate::{BaseVersionParser, FullVersion, ParserError};
-use std::fmt;
+use crate::parsers::modular::{self, ModularParserError};
+use crate::version::increment;
+use crate::{BaseVersionParser, BumpError, BumpMode, Component, FullVersion, ParserError};
+use core::fmt;
 
 /// A two-component `MAJOR.MINOR` version.
 ///
@@ -37,7 +38,7 @@ impl BaseVersion {
     /// See [`BaseVersion`] for more.
     ///
     /// [`BaseVersion`]: crate::BaseVersion
-    pub fn new(major: u64, minor: u64) -> Self {
+    pub const fn new(major: u64, minor: u64) -> Self {
         Self { major, minor }
     }
 
@@ -48,11 +49,35 @@ impl BaseVersion {
         modular::ModularParser.parse_base(input)
     }
 
+    /// Parse a two component, `major.minor` version number from a given input, in a `const`
+    /// context.
+    ///
+    /// Follows the same rules as [`BaseVersion::parse`], but returns the [`ModularParserError`]
+    /// of the modular parser if it fails to parse. See the [`version!`] macro to check a version
+    /// literal at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::BaseVersion;
+    ///
+    /// const MSRV: Result<BaseVersion, version_number::parsers::modular::ModularParserError> =
+    ///     BaseVersion::parse_const("1.56");
+    ///
+    /// assert_eq!(MSRV, Ok(BaseVersion::new(1, 56)));
+    /// ```
+    ///
+    /// [`ModularParserError`]: crate::parsers::modular::ModularParserError
+    /// [`version!`]: crate::version
+    pub const fn parse_const(input: &str) -> Result<Self, ModularParserError> {
+        modular::constant::parse_base(input.as_bytes())
+    }
+
     /// Convert this base version to a full version.
     ///
     /// This conversion is lossy because the `patch` value is not known to this BaseVersion, and
     /// will initialize as `0`.
-    pub fn to_full_version_lossy(self) -> FullVersion {
+    pub const fn to_full_version_lossy(self) -> FullVersion {
         FullVersion {
             major: self.major,
             minor: self.minor,
@@ -82,6 +107,112 @@ impl BaseVersion {
     {
         fun(self)
     }
+
+    /// Increment the `major` component, and reset the `minor` component to `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `major` component equals `u64::MAX`. See [`BaseVersion::checked_bump_major`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::BaseVersion;
+    ///
+    /// assert_eq!(BaseVersion::new(1, 2).bump_major(), BaseVersion::new(2, 0));
+    /// ```
+    pub fn bump_major(self) -> Self {
+        self.checked_bump_major()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `minor` component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `minor` component equals `u64::MAX`. See [`BaseVersion::checked_bump_minor`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::BaseVersion;
+    ///
+    /// assert_eq!(BaseVersion::new(1, 2).bump_minor(), BaseVersion::new(1, 3));
+    /// ```
+    pub fn bump_minor(self) -> Self {
+        self.checked_bump_minor()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the implicit `patch` component, which produces a [`FullVersion`].
+    ///
+    /// Since a base version does not have a `patch` component, it is considered to be `0`.
+    /// Consequently, this method can't overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::{BaseVersion, FullVersion};
+    ///
+    /// assert_eq!(BaseVersion::new(1, 2).bump_patch(), FullVersion::new(1, 2, 1));
+    /// ```
+    pub fn bump_patch(self) -> FullVersion {
+        FullVersion::new(self.major, self.minor, 1)
+    }
+
+    /// Bump the version for a backwards incompatible change. The [`BumpMode`] determines
+    /// whether the `major`, or for `0.y` versions the `minor`, component is incremented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the incremented component equals `u64::MAX`. See
+    /// [`BaseVersion::checked_bump_breaking`] for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::{BaseVersion, BumpMode};
+    ///
+    /// let version = BaseVersion::new(0, 2);
+    ///
+    /// assert_eq!(version.bump_breaking(BumpMode::Standard), BaseVersion::new(1, 0));
+    /// assert_eq!(version.bump_breaking(BumpMode::InitialDevelopment), BaseVersion::new(0, 3));
+    /// ```
+    pub fn bump_breaking(self, mode: BumpMode) -> Self {
+        self.checked_bump_breaking(mode)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `major` component, and reset the `minor` component to `0`.
+    ///
+    /// Returns a [`BumpError`] if the `major` component would overflow.
+    pub fn checked_bump_major(self) -> Result<Self, BumpError> {
+        let major = increment(self.major, Component::Major)?;
+
+        Ok(Self::new(major, 0))
+    }
+
+    /// Increment the `minor` component.
+    ///
+    /// Returns a [`BumpError`] if the `minor` component would overflow.
+    pub fn checked_bump_minor(self) -> Result<Self, BumpError> {
+        let minor = increment(self.minor, Component::Minor)?;
+
+        Ok(Self::new(self.major, minor))
+    }
+
+    /// Bump the version for a backwards incompatible change, as described by
+    /// [`BaseVersion::bump_breaking`].
+    ///
+    /// Returns a [`BumpError`] if the incremented component would overflow.
+    pub fn checked_bump_breaking(self, mode: BumpMode) -> Result<Self, BumpError> {
+        match mode {
+            BumpMode::InitialDevelopment if self.major == 0 => self.checked_bump_minor(),
+            _ => self.checked_bump_major(),
+        }
+    }
 }
 
 impl From<(u64, u64)> for BaseVersion {
@@ -151,6 +282,40 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod bump_tests {
+    use crate::{BaseVersion, BumpError, BumpMode, Component, FullVersion};
+
+    #[yare::parameterized(
+        major = { BaseVersion::new(1, 2).bump_major(), BaseVersion::new(2, 0) },
+        minor = { BaseVersion::new(1, 2).bump_minor(), BaseVersion::new(1, 3) },
+        breaking_standard = { BaseVersion::new(0, 2).bump_breaking(BumpMode::Standard), BaseVersion::new(1, 0) },
+        breaking_initial_development = { BaseVersion::new(0, 2).bump_breaking(BumpMode::InitialDevelopment), BaseVersion::new(0, 3) },
+    )]
+    fn bump(bumped: BaseVersion, expected: BaseVersion) {
+        assert_eq!(bumped, expected);
+    }
+
+    #[test]
+    fn bump_patch() {
+        let version = BaseVersion::new(u64::MAX, u64::MAX);
+
+        assert_eq!(
+            version.bump_patch(),
+            FullVersion::new(u64::MAX, u64::MAX, 1)
+        );
+    }
+
+    #[yare::parameterized(
+        major = { BaseVersion::new(u64::MAX, 0).checked_bump_major(), Component::Major },
+        minor = { BaseVersion::new(0, u64::MAX).checked_bump_minor(), Component::Minor },
+        breaking = { BaseVersion::new(u64::MAX, 0).checked_bump_breaking(BumpMode::InitialDevelopment), Component::Major },
+    )]
+    fn checked_overflow(result: Result<BaseVersion, BumpError>, component: Component) {
+        assert_eq!(result.unwrap_err(), BumpError::Overflow { component });
+    }
+}
+
 #[cfg(test)]
 mod ord_tests {
     use crate::BaseVersion;
@@ -213,9 +378,8 @@ mod partial_ord_tests {
 
 #[cfg(test)]
 mod parse_base {
-    use crate::parsers::error::ExpectedError;
-    use crate::parsers::NumericError;
-    use crate::{BaseVersion, ParserError};
+    use crate::parsers::{NumericError, Span, Token};
+    use crate::BaseVersion;
 
     #[test]
     fn ok() {
@@ -226,31 +390,39 @@ mod parse_base {
 
     #[test]
     fn err_on_major_only() {
-        let result = BaseVersion::parse("1");
+        let err = BaseVersion::parse("1").unwrap_err();
 
-        assert!(matches!(
-            result.unwrap_err(),
-            ParserError::Expected(ExpectedError::Separator { .. })
-        ));
+        assert_eq!(err.span(), Span::empty(1));
+        assert_eq!(err.got(), None);
+        assert!(err.expected().contains(Token::Dot));
     }
 
     #[test]
     fn err_on_not_finished() {
-        let result = BaseVersion::parse("1.2.3");
+        let err = BaseVersion::parse("1.2.3").unwrap_err();
 
-        assert!(matches!(
-            result.unwrap_err(),
-            ParserError::Expected(ExpectedError::EndOfInput { .. })
-        ));
+        assert_eq!(err.span(), Span::new(3, 4));
+        assert_eq!(err.got(), Some('.'));
+        assert!(err.expected().contains(Token::EndOfInput));
     }
 
     #[test]
     fn err_on_starts_with_0() {
-        let result = BaseVersion::parse("1.02");
+        let err = BaseVersion::parse("1.02").unwrap_err();
 
-        assert!(matches!(
-            result.unwrap_err(),
-            ParserError::Numeric(NumericError::LeadingZero)
-        ));
+        assert_eq!(*err.reason(), NumericError::LeadingZero.into());
+        assert_eq!(err.span(), Span::new(2, 4));
+    }
+
+    #[test]
+    fn err_got_is_decoded() {
+        let err = BaseVersion::parse("1.2é").unwrap_err();
+
+        assert_eq!(err.got(), Some('é'));
+        assert_eq!(err.span(), Span::new(3, 5));
+        assert_eq!(
+            err.to_string(),
+            "Expected 0-9 or EOI, but got 'é' (at 3..5)"
+        );
     }
 }
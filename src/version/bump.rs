@@ -0,0 +1,81 @@
+use core::fmt;
+
+/// Determines which component is incremented by a _breaking_ bump, such as by
+/// [`FullVersion::bump_breaking`].
+///
+/// [`FullVersion::bump_breaking`]: crate::FullVersion::bump_breaking
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BumpMode {
+    /// A breaking bump always increments the `major` component.
+    ///
+    /// For example, `0.2.3` is bumped to `1.0.0`, and `1.2.3` to `2.0.0`.
+    Standard,
+    /// A breaking bump of an *unstable initial development version*, i.e. a version with
+    /// a `major` component of `0`, increments the `minor` component instead.
+    ///
+    /// For example, `0.2.3` is bumped to `0.3.0`, while `1.2.3` is still bumped to `2.0.0`.
+    /// This matches how Cargo treats `0.y` versions.
+    InitialDevelopment,
+}
+
+impl Default for BumpMode {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// A component of a version number.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Component {
+    /// The `major` version component.
+    Major,
+    /// The `minor` version component.
+    Minor,
+    /// The `patch` version component.
+    Patch,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Patch => "patch",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// An error which may be returned when bumping a version.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BumpError {
+    /// This error variant is returned if the bumped component would overflow, i.e. if it already
+    /// equals `u64::MAX`.
+    Overflow {
+        /// The component which would overflow.
+        component: Component,
+    },
+}
+
+impl fmt::Display for BumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow { component } => write!(
+                f,
+                "Overflow: Unable to bump the {} component, since it would be larger than the maximum supported number (max={})",
+                component,
+                u64::MAX
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BumpError {}
+
+pub(crate) fn increment(value: u64, component: Component) -> Result<u64, BumpError> {
+    value
+        .checked_add(1)
+        .ok_or(BumpError::Overflow { component })
+}
@@ -0,0 +1,227 @@
+use crate::parsers::modular;
+use crate::{BaseVersion, FullVersion, ParserError};
+use core::fmt;
+
+/// A version which consists of exactly `N` components, like the four component `1.2.3.4` of a
+/// Windows or .NET assembly version, or the Chromium version `120.0.6099.109`.
+///
+/// The components are parsed by the same rules as those of a [`BaseVersion`] and a
+/// [`FullVersion`], so components with leading zeros, or which overflow a `u64`, are rejected.
+/// A `ComponentVersion<2>` may be converted from and to a [`BaseVersion`], and a
+/// `ComponentVersion<3>` from and to a [`FullVersion`].
+///
+/// # Ordering
+///
+/// Versions are ordered lexicographically by their components, from left to right.
+///
+/// # Example
+///
+/// ```
+/// use version_number::{ComponentVersion, FullVersion};
+///
+/// let version = ComponentVersion::<4>::parse("120.0.6099.109").unwrap();
+///
+/// assert_eq!(version.components, [120, 0, 6099, 109]);
+/// assert!(version < ComponentVersion::new([120, 0, 6099, 110]));
+///
+/// let full = ComponentVersion::<3>::parse("1.2.3").unwrap();
+/// assert_eq!(FullVersion::from(full), FullVersion::new(1, 2, 3));
+/// ```
+///
+/// [`BaseVersion`]: crate::BaseVersion
+/// [`FullVersion`]: crate::FullVersion
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ComponentVersion<const N: usize> {
+    /// The components of the version, from the leading (most significant) component to the last.
+    pub components: [u64; N],
+}
+
+impl<const N: usize> ComponentVersion<N> {
+    /// Instantiate a version number with the given components.
+    pub const fn new(components: [u64; N]) -> Self {
+        Self { components }
+    }
+
+    /// Parse a version number which consists of exactly `N` components from a given input.
+    ///
+    /// Returns a [`ParserError`] if it fails to parse.
+    pub fn parse(input: &str) -> Result<Self, ParserError> {
+        Self::parse_padded(input, N)
+    }
+
+    /// Parse a version number which consists of at least `min` and at most `N` components from
+    /// a given input. The components which are not present in the input are `0`.
+    ///
+    /// See [`modular::Parser::parse_components`] for more.
+    ///
+    /// Returns a [`ParserError`] if it fails to parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::ComponentVersion;
+    ///
+    /// let version = ComponentVersion::<4>::parse_padded("1.2.3", 2).unwrap();
+    ///
+    /// assert_eq!(version, ComponentVersion::new([1, 2, 3, 0]));
+    /// assert!(ComponentVersion::<4>::parse_padded("1", 2).is_err());
+    /// ```
+    ///
+    /// [`modular::Parser::parse_components`]: crate::parsers::modular::Parser::parse_components
+    pub fn parse_padded(input: &str, min: usize) -> Result<Self, ParserError> {
+        let input = input.as_bytes();
+
+        modular::Parser::from_slice(input)
+            .parse_components(min)
+            .map_err(|e| ParserError::from(e).decode_got(input))
+    }
+}
+
+impl<const N: usize> From<[u64; N]> for ComponentVersion<N> {
+    fn from(components: [u64; N]) -> Self {
+        Self::new(components)
+    }
+}
+
+impl From<BaseVersion> for ComponentVersion<2> {
+    fn from(version: BaseVersion) -> Self {
+        Self::new([version.major, version.minor])
+    }
+}
+
+impl From<ComponentVersion<2>> for BaseVersion {
+    fn from(version: ComponentVersion<2>) -> Self {
+        let [major, minor] = version.components;
+
+        BaseVersion::new(major, minor)
+    }
+}
+
+impl From<FullVersion> for ComponentVersion<3> {
+    fn from(version: FullVersion) -> Self {
+        Self::new([version.major, version.minor, version.patch])
+    }
+}
+
+impl From<ComponentVersion<3>> for FullVersion {
+    fn from(version: ComponentVersion<3>) -> Self {
+        let [major, minor, patch] = version.components;
+
+        FullVersion::new(major, minor, patch)
+    }
+}
+
+impl<const N: usize> fmt::Display for ComponentVersion<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            write!(f, "{}", component)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::{NumericError, Span, Token};
+    use crate::{BaseVersion, ComponentVersion, FullVersion};
+    use std::cmp::Ordering;
+
+    #[yare::parameterized(
+        four = { "1.2.3.4", [1, 2, 3, 4] },
+        chromium = { "120.0.6099.109", [120, 0, 6099, 109] },
+        zeros = { "0.0.0.0", [0, 0, 0, 0] },
+        max = { "18446744073709551615.0.0.0", [u64::MAX, 0, 0, 0] },
+    )]
+    fn parse(input: &str, components: [u64; 4]) {
+        let version = ComponentVersion::<4>::parse(input).unwrap();
+
+        assert_eq!(version.components, components);
+        assert_eq!(version.to_string(), input);
+    }
+
+    #[test]
+    fn parse_err_on_too_few() {
+        let err = ComponentVersion::<4>::parse("1.2.3").unwrap_err();
+
+        assert_eq!(err.span(), Span::empty(5));
+        assert!(err.expected().contains(Token::Dot));
+    }
+
+    #[test]
+    fn parse_err_on_too_many() {
+        let err = ComponentVersion::<4>::parse("1.2.3.4.5").unwrap_err();
+
+        assert_eq!(err.span(), Span::new(7, 8));
+        assert!(err.expected().contains(Token::EndOfInput));
+    }
+
+    #[test]
+    fn parse_err_on_starts_with_0() {
+        let err = ComponentVersion::<4>::parse("1.2.3.04").unwrap_err();
+
+        assert_eq!(*err.reason(), NumericError::LeadingZero.into());
+        assert_eq!(err.span(), Span::new(6, 8));
+    }
+
+    #[yare::parameterized(
+        min = { "1.2", [1, 2, 0, 0] },
+        between = { "1.2.3", [1, 2, 3, 0] },
+        max = { "1.2.3.4", [1, 2, 3, 4] },
+    )]
+    fn parse_padded(input: &str, components: [u64; 4]) {
+        let version = ComponentVersion::<4>::parse_padded(input, 2).unwrap();
+
+        assert_eq!(version.components, components);
+    }
+
+    #[test]
+    fn parse_padded_err_on_non_ascii() {
+        let err = ComponentVersion::<4>::parse_padded("1.2é", 2).unwrap_err();
+
+        assert_eq!(err.got(), Some('é'));
+        assert_eq!(err.span(), Span::new(3, 5));
+        assert!(err.expected().contains(Token::Dot));
+    }
+
+    #[yare::parameterized(
+        last = { [1, 2, 3, 4], [1, 2, 3, 5] },
+        first = { [1, 9, 9, 9], [2, 0, 0, 0] },
+        middle = { [1, 2, 9, 9], [1, 3, 0, 0] },
+    )]
+    fn less(lhs: [u64; 4], rhs: [u64; 4]) {
+        let lhs = ComponentVersion::new(lhs);
+        let rhs = ComponentVersion::new(rhs);
+
+        assert_eq!(lhs.cmp(&rhs), Ordering::Less);
+        assert_eq!(rhs.partial_cmp(&lhs), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn base_version_round_trip() {
+        let version = ComponentVersion::from(BaseVersion::new(1, 2));
+
+        assert_eq!(version.components, [1, 2]);
+        assert_eq!(BaseVersion::from(version), BaseVersion::new(1, 2));
+    }
+
+    #[test]
+    fn full_version_round_trip() {
+        let version = ComponentVersion::from(FullVersion::new(1, 2, 3));
+
+        assert_eq!(version.components, [1, 2, 3]);
+        assert_eq!(FullVersion::from(version), FullVersion::new(1, 2, 3));
+    }
+
+    #[test]
+    fn from_array() {
+        assert_eq!(
+            ComponentVersion::from([1, 2, 3, 4]),
+            ComponentVersion::new([1, 2, 3, 4])
+        );
+    }
+}
@@ -1,6 +1,7 @@
-use crate::parsers::modular;
-use crate::{BaseVersion, FullVersionParser, ParserError};
-use std::fmt;
+use crate::parsers::modular::{self, ModularParserError};
+use crate::version::increment;
+use crate::{BaseVersion, BumpError, BumpMode, Component, FullVersionParser, ParserError};
+use core::fmt;
 
 /// A three-component `MAJOR.MINOR.PATCH` version.
 ///
@@ -16,7 +17,8 @@ use std::fmt;
 /// # Converting to a semver::Version
 ///
 /// This version type may be converted to a [`semver::Version`] using the [`From`] trait, assuming
-/// the `semver` feature is enabled.
+/// the `semver` feature is enabled. A [`semver::Version`] without pre-release label and build
+/// metadata may be converted back using the `TryFrom` trait.
 ///
 ///
 /// [`semver`]: https://semver.org/spec/v2.0.0.html
@@ -48,7 +50,7 @@ impl FullVersion {
     /// See [`FullVersion`] for more.
     ///
     /// [`FullVersion`]: crate::FullVersion
-    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
         Self {
             major,
             minor,
@@ -63,10 +65,23 @@ impl FullVersion {
         modular::ModularParser.parse_full(input)
     }
 
+    /// Parse a three component, `major.minor.patch` version number from a given input, in a
+    /// `const` context.
+    ///
+    /// Follows the same rules as [`FullVersion::parse`], but returns the [`ModularParserError`]
+    /// of the modular parser if it fails to parse. See the [`version!`] macro to check a version
+    /// literal at compile time.
+    ///
+    /// [`ModularParserError`]: crate::parsers::modular::ModularParserError
+    /// [`version!`]: crate::version
+    pub const fn parse_const(input: &str) -> Result<Self, ModularParserError> {
+        modular::constant::parse_full(input.as_bytes())
+    }
+
     /// Convert this full version to a base version.
     ///
     /// This conversion is lossy because the `patch` value is lost upon conversion.
-    pub fn to_base_version_lossy(self) -> BaseVersion {
+    pub const fn to_base_version_lossy(self) -> BaseVersion {
         BaseVersion {
             major: self.major,
             minor: self.minor,
@@ -95,29 +110,123 @@ impl FullVersion {
     {
         fun(self)
     }
-}
 
-#[cfg(feature = "semver")]
-impl From<FullVersion> for semver::Version {
-    /// Convert the given [`FullVersion`] to a [`semver::Version`].
+    /// Increment the `major` component, and reset the `minor` and `patch` components to `0`.
     ///
-    /// Requires the `semver` feature to be enabled.
+    /// # Panics
+    ///
+    /// Panics if the `major` component equals `u64::MAX`. See [`FullVersion::checked_bump_major`]
+    /// for a non-panicking alternative.
     ///
     /// # Example
     ///
     /// ```
-    /// # use version_number::FullVersion;
+    /// use version_number::FullVersion;
     ///
-    /// let version = FullVersion::new(1, 2, 3);
-    /// let converted: semver::Version = version.into();
+    /// assert_eq!(FullVersion::new(1, 2, 3).bump_major(), FullVersion::new(2, 0, 0));
+    /// ```
+    pub fn bump_major(self) -> Self {
+        self.checked_bump_major()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `minor` component, and reset the `patch` component to `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `minor` component equals `u64::MAX`. See [`FullVersion::checked_bump_minor`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Example
     ///
-    /// assert_eq!(converted, semver::Version::new(1, 2, 3));
     /// ```
+    /// use version_number::FullVersion;
     ///
-    /// [`FullVersion`]: crate::FullVersion
-    /// [`semver::Version`]: https://docs.rs/semver/1/semver/struct.Version.html
-    fn from(version: FullVersion) -> Self {
-        semver::Version::new(version.major, version.minor, version.patch)
+    /// assert_eq!(FullVersion::new(1, 2, 3).bump_minor(), FullVersion::new(1, 3, 0));
+    /// ```
+    pub fn bump_minor(self) -> Self {
+        self.checked_bump_minor()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `patch` component.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `patch` component equals `u64::MAX`. See [`FullVersion::checked_bump_patch`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::FullVersion;
+    ///
+    /// assert_eq!(FullVersion::new(1, 2, 3).bump_patch(), FullVersion::new(1, 2, 4));
+    /// ```
+    pub fn bump_patch(self) -> Self {
+        self.checked_bump_patch()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Bump the version for a backwards incompatible change. The [`BumpMode`] determines
+    /// whether the `major`, or for `0.y.z` versions the `minor`, component is incremented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the incremented component equals `u64::MAX`. See
+    /// [`FullVersion::checked_bump_breaking`] for a non-panicking alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use version_number::{BumpMode, FullVersion};
+    ///
+    /// let version = FullVersion::new(0, 2, 3);
+    ///
+    /// assert_eq!(version.bump_breaking(BumpMode::Standard), FullVersion::new(1, 0, 0));
+    /// assert_eq!(version.bump_breaking(BumpMode::InitialDevelopment), FullVersion::new(0, 3, 0));
+    /// ```
+    pub fn bump_breaking(self, mode: BumpMode) -> Self {
+        self.checked_bump_breaking(mode)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Increment the `major` component, and reset the `minor` and `patch` components to `0`.
+    ///
+    /// Returns a [`BumpError`] if the `major` component would overflow.
+    pub fn checked_bump_major(self) -> Result<Self, BumpError> {
+        let major = increment(self.major, Component::Major)?;
+
+        Ok(Self::new(major, 0, 0))
+    }
+
+    /// Increment the `minor` component, and reset the `patch` component to `0`.
+    ///
+    /// Returns a [`BumpError`] if the `minor` component would overflow.
+    pub fn checked_bump_minor(self) -> Result<Self, BumpError> {
+        let minor = increment(self.minor, Component::Minor)?;
+
+        Ok(Self::new(self.major, minor, 0))
+    }
+
+    /// Increment the `patch` component.
+    ///
+    /// Returns a [`BumpError`] if the `patch` component would overflow.
+    pub fn checked_bump_patch(self) -> Result<Self, BumpError> {
+        let patch = increment(self.patch, Component::Patch)?;
+
+        Ok(Self::new(self.major, self.minor, patch))
+    }
+
+    /// Bump the version for a backwards incompatible change, as described by
+    /// [`FullVersion::bump_breaking`].
+    ///
+    /// Returns a [`BumpError`] if the incremented component would overflow.
+    pub fn checked_bump_breaking(self, mode: BumpMode) -> Result<Self, BumpError> {
+        match mode {
+            BumpMode::InitialDevelopment if self.major == 0 => self.checked_bump_minor(),
+            _ => self.checked_bump_major(),
+        }
     }
 }
 
@@ -189,6 +298,49 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod bump_tests {
+    use crate::{BumpError, BumpMode, Component, FullVersion};
+
+    #[yare::parameterized(
+        major = { FullVersion::new(1, 2, 3).bump_major(), FullVersion::new(2, 0, 0) },
+        minor = { FullVersion::new(1, 2, 3).bump_minor(), FullVersion::new(1, 3, 0) },
+        patch = { FullVersion::new(1, 2, 3).bump_patch(), FullVersion::new(1, 2, 4) },
+        breaking_standard = { FullVersion::new(0, 2, 3).bump_breaking(BumpMode::Standard), FullVersion::new(1, 0, 0) },
+        breaking_initial_development = { FullVersion::new(0, 2, 3).bump_breaking(BumpMode::InitialDevelopment), FullVersion::new(0, 3, 0) },
+        breaking_initial_development_stable = { FullVersion::new(1, 2, 3).bump_breaking(BumpMode::InitialDevelopment), FullVersion::new(2, 0, 0) },
+    )]
+    fn bump(bumped: FullVersion, expected: FullVersion) {
+        assert_eq!(bumped, expected);
+    }
+
+    #[yare::parameterized(
+        major = { FullVersion::new(u64::MAX, 0, 0).checked_bump_major(), Component::Major },
+        minor = { FullVersion::new(0, u64::MAX, 0).checked_bump_minor(), Component::Minor },
+        patch = { FullVersion::new(0, 0, u64::MAX).checked_bump_patch(), Component::Patch },
+        breaking = { FullVersion::new(0, u64::MAX, 0).checked_bump_breaking(BumpMode::InitialDevelopment), Component::Minor },
+    )]
+    fn checked_overflow(result: Result<FullVersion, BumpError>, component: Component) {
+        assert_eq!(result.unwrap_err(), BumpError::Overflow { component });
+    }
+
+    #[test]
+    fn checked_ok_below_max() {
+        let version = FullVersion::new(u64::MAX - 1, u64::MAX, u64::MAX);
+
+        assert_eq!(
+            version.checked_bump_major().unwrap(),
+            FullVersion::new(u64::MAX, 0, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unable to bump the patch component")]
+    fn bump_overflow_panics() {
+        let _ = FullVersion::new(0, 0, u64::MAX).bump_patch();
+    }
+}
+
 #[cfg(test)]
 mod ord_tests {
     use crate::FullVersion;
@@ -255,9 +407,8 @@ mod partial_ord_tests {
 
 #[cfg(test)]
 mod parse_full {
-    use crate::parsers::error::ExpectedError;
-    use crate::parsers::NumericError;
-    use crate::{FullVersion, ParserError};
+    use crate::parsers::{NumericError, Span, Token};
+    use crate::FullVersion;
 
     #[test]
     fn ok() {
@@ -268,31 +419,27 @@ mod parse_full {
 
     #[test]
     fn err_on_base_only() {
-        let result = FullVersion::parse("1.2");
+        let err = FullVersion::parse("1.2").unwrap_err();
 
-        assert!(matches!(
-            result.unwrap_err(),
-            ParserError::Expected(ExpectedError::Separator { .. })
-        ));
+        assert_eq!(err.span(), Span::empty(3));
+        assert_eq!(err.got(), None);
+        assert!(err.expected().contains(Token::Dot));
     }
 
     #[test]
     fn err_on_not_finished() {
-        let result = FullVersion::parse("1.2.3.");
+        let err = FullVersion::parse("1.2.3.").unwrap_err();
 
-        assert!(matches!(
-            result.unwrap_err(),
-            ParserError::Expected(ExpectedError::EndOfInput { .. })
-        ));
+        assert_eq!(err.span(), Span::new(5, 6));
+        assert_eq!(err.got(), Some('.'));
+        assert!(err.expected().contains(Token::EndOfInput));
     }
 
     #[test]
     fn err_on_starts_with_0() {
-        let result = FullVersion::parse("1.2.03");
+        let err = FullVersion::parse("1.2.03").unwrap_err();
 
-        assert!(matches!(
-            result.unwrap_err(),
-            ParserError::Numeric(NumericError::LeadingZero)
-        ));
+        assert_eq!(*err.reason(), NumericError::LeadingZero.into());
+        assert_eq!(err.span(), Span::new(4, 6));
     }
 }
@@ -0,0 +1,379 @@
+use crate::parsers::labels;
+use crate::parsers::original;
+use crate::parsers::LabelError;
+use crate::{BaseVersion, Error, FullVersion, ParserError, Version};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A [`Version`] which may be extended with a pre-release label, and build metadata.
+///
+/// For example, `1.70.0-beta.3`, `1.2.3+build.5` and `1.70-nightly` are labeled versions.
+/// The version numbers accepted by [`Version`], [`BaseVersion`] and [`FullVersion`] do not
+/// accept these labels.
+///
+/// # Ordering
+///
+/// Labeled versions are ordered by their [`Version`] first, and their [`Prerelease`] label
+/// second, according to the [`semver`] precedence rules. As a result, a version with a
+/// pre-release label is ordered before the same version without one, e.g. `1.0.0-rc.1` is less
+/// than `1.0.0`.
+///
+/// The [`BuildMetadata`] is ignored when determining precedence. To stay consistent with the
+/// ordering, it is also ignored by the [`Eq`] and [`Hash`] implementations, so `1.2.3+a` equals
+/// `1.2.3+b`.
+///
+/// # Example
+///
+/// ```
+/// use version_number::{LabeledVersion, Version};
+///
+/// let beta = LabeledVersion::parse("1.70.0-beta.3").unwrap();
+/// let stable = LabeledVersion::parse("1.70.0").unwrap();
+///
+/// assert_eq!(beta.version, Version::new_full_version(1, 70, 0));
+/// assert_eq!(beta.pre.as_str(), "beta.3");
+/// assert!(beta < stable);
+/// ```
+///
+/// [`semver`]: https://semver.org/spec/v2.0.0.html#spec-item-11
+#[derive(Clone, Debug)]
+pub struct LabeledVersion {
+    /// The version number, without its labels.
+    pub version: Version,
+    /// The pre-release label, which is empty if the version has none.
+    pub pre: Prerelease,
+    /// The build metadata, which is empty if the version has none.
+    pub build: BuildMetadata,
+}
+
+impl LabeledVersion {
+    /// Create a new labeled version from a version number, pre-release label and build metadata.
+    pub fn new(version: Version, pre: Prerelease, build: BuildMetadata) -> Self {
+        Self {
+            version,
+            pre,
+            build,
+        }
+    }
+
+    /// Parse a two- or three-component version number, optionally followed by a pre-release label
+    /// and/or build metadata, from a given input.
+    ///
+    /// Returns a [`Error::ParserError`] if it fails to parse.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        original::Parser::from_slice(input.as_bytes())
+            .parse_labeled()
+            .map_err(|e| Error::from(ParserError::from(e)))
+    }
+}
+
+impl PartialEq for LabeledVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version && self.pre == other.pre
+    }
+}
+
+impl Eq for LabeledVersion {}
+
+impl Hash for LabeledVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.pre.hash(state);
+    }
+}
+
+impl Ord for LabeledVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version
+            .cmp(&other.version)
+            .then_with(|| self.pre.cmp(&other.pre))
+    }
+}
+
+impl PartialOrd for LabeledVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for LabeledVersion {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for LabeledVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.version, f)?;
+
+        if !self.pre.is_empty() {
+            f.write_fmt(format_args!("-{}", self.pre))?;
+        }
+
+        if !self.build.is_empty() {
+            f.write_fmt(format_args!("+{}", self.build))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Version> for LabeledVersion {
+    fn from(version: Version) -> Self {
+        Self::new(version, Prerelease::EMPTY, BuildMetadata::EMPTY)
+    }
+}
+
+impl From<BaseVersion> for LabeledVersion {
+    fn from(version: BaseVersion) -> Self {
+        Self::from(Version::Base(version))
+    }
+}
+
+impl From<FullVersion> for LabeledVersion {
+    fn from(version: FullVersion) -> Self {
+        Self::from(Version::Full(version))
+    }
+}
+
+/// A pre-release label, such as the `beta.3` in `1.70.0-beta.3`.
+///
+/// A pre-release label consists of one or more dot separated identifiers. Each identifier
+/// consists of ASCII alphanumerics and hyphens, and may not be empty. Numeric identifiers
+/// may not start with a leading zero.
+///
+/// Pre-release labels are ordered according to the [`semver`] precedence rules: identifiers
+/// are compared from left to right, where numeric identifiers are compared numerically,
+/// and alphanumeric identifiers are compared lexically in ASCII sort order. Numeric identifiers
+/// have lower precedence than alphanumeric identifiers. An empty label, i.e. the absence of a
+/// pre-release, has a higher precedence than any non-empty label.
+///
+/// [`semver`]: https://semver.org/spec/v2.0.0.html#spec-item-11
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Prerelease(String);
+
+impl Prerelease {
+    /// The absence of a pre-release label.
+    pub const EMPTY: Prerelease = Prerelease(String::new());
+
+    /// Create a pre-release label from the given input, without the leading `-`.
+    ///
+    /// Returns a [`LabelError`] if the input is not a valid pre-release label.
+    ///
+    /// [`LabelError`]: crate::parsers::LabelError
+    pub fn new(input: &str) -> Result<Self, LabelError> {
+        labels::parse_label(input, labels::Kind::Prerelease).map(Self)
+    }
+
+    pub(crate) fn new_unchecked(label: String) -> Self {
+        Self(label)
+    }
+
+    /// Returns the pre-release label as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the label is empty, i.e. whether there is no pre-release.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Ord for Prerelease {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+
+        let mut lhs = self.0.split('.');
+        let mut rhs = other.0.split('.');
+
+        loop {
+            match (lhs.next(), rhs.next()) {
+                (Some(lhs), Some(rhs)) => match cmp_identifier(lhs, rhs) {
+                    Ordering::Equal => {}
+                    ordering => return ordering,
+                },
+                (lhs, rhs) => return lhs.is_some().cmp(&rhs.is_some()),
+            }
+        }
+    }
+}
+
+impl PartialOrd for Prerelease {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Prerelease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Numeric identifiers can't have leading zeros, so a longer numeric identifier is always
+// larger. This way we don't have to be concerned about the size of the number.
+fn cmp_identifier(lhs: &str, rhs: &str) -> Ordering {
+    let is_numeric = |identifier: &str| identifier.bytes().all(|b| b.is_ascii_digit());
+
+    match (is_numeric(lhs), is_numeric(rhs)) {
+        (true, true) => lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs)),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => lhs.cmp(rhs),
+    }
+}
+
+/// Build metadata, such as the `build.5` in `1.2.3+build.5`.
+///
+/// Build metadata consists of one or more dot separated identifiers. Each identifier
+/// consists of ASCII alphanumerics and hyphens, and may not be empty. Unlike pre-release
+/// identifiers, numeric identifiers may start with a leading zero.
+///
+/// Build metadata is ignored when determining the precedence of a [`LabeledVersion`].
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BuildMetadata(String);
+
+impl BuildMetadata {
+    /// The absence of build metadata.
+    pub const EMPTY: BuildMetadata = BuildMetadata(String::new());
+
+    /// Create build metadata from the given input, without the leading `+`.
+    ///
+    /// Returns a [`LabelError`] if the input is not valid build metadata.
+    ///
+    /// [`LabelError`]: crate::parsers::LabelError
+    pub fn new(input: &str) -> Result<Self, LabelError> {
+        labels::parse_label(input, labels::Kind::BuildMetadata).map(Self)
+    }
+
+    pub(crate) fn new_unchecked(label: String) -> Self {
+        Self(label)
+    }
+
+    /// Returns the build metadata as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the build metadata is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for BuildMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::LabelError;
+    use crate::{BuildMetadata, LabeledVersion, Prerelease, Version};
+
+    #[yare::parameterized(
+        base = { "1.70", Version::new_base_version(1, 70), "", "" },
+        full = { "1.70.0", Version::new_full_version(1, 70, 0), "", "" },
+        pre = { "1.70.0-beta.3", Version::new_full_version(1, 70, 0), "beta.3", "" },
+        base_pre = { "1.70-nightly", Version::new_base_version(1, 70), "nightly", "" },
+        build = { "1.2.3+build.5", Version::new_full_version(1, 2, 3), "", "build.5" },
+        pre_and_build = { "1.2.3-rc.1+build.05", Version::new_full_version(1, 2, 3), "rc.1", "build.05" },
+        hyphens = { "1.2.3--x-y-+-", Version::new_full_version(1, 2, 3), "-x-y-", "-" },
+    )]
+    fn parse(input: &str, version: Version, pre: &str, build: &str) {
+        let labeled = LabeledVersion::parse(input).unwrap();
+
+        assert_eq!(labeled.version, version);
+        assert_eq!(labeled.pre.as_str(), pre);
+        assert_eq!(labeled.build.as_str(), build);
+        assert_eq!(labeled.to_string(), input);
+    }
+
+    #[yare::parameterized(
+        empty_pre = { "1.2.3-" },
+        empty_build = { "1.2.3+" },
+        empty_identifier = { "1.2.3-a..b" },
+        trailing_dot = { "1.2.3-a." },
+        leading_zero = { "1.2.3-01" },
+        invalid_char = { "1.2.3-a_b" },
+        unicode = { "1.2.3-ä" },
+        two_builds = { "1.2.3+a+b" },
+        no_separator = { "1.2.3a" },
+        version_leading_zero = { "1.02.3-a" },
+    )]
+    fn parse_rejected(input: &str) {
+        assert!(LabeledVersion::parse(input).is_err());
+    }
+
+    #[test]
+    fn strict_version_rejects_labels() {
+        assert!(Version::parse("1.70.0-beta.3").is_err());
+        assert!(Version::parse("1.2.3+build.5").is_err());
+    }
+
+    #[test]
+    fn semver_precedence() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1-0",
+            "1.0.1",
+        ]
+        .iter()
+        .map(|input| LabeledVersion::parse(input).unwrap())
+        .collect::<Vec<_>>();
+
+        for window in ordered.windows(2) {
+            assert!(window[0] < window[1], "{} < {}", window[0], window[1]);
+        }
+    }
+
+    #[test]
+    fn build_metadata_is_ignored() {
+        let lhs = LabeledVersion::parse("1.2.3-rc.1+a").unwrap();
+        let rhs = LabeledVersion::parse("1.2.3-rc.1+b").unwrap();
+
+        assert_eq!(lhs, rhs);
+        assert_eq!(lhs.cmp(&rhs), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn from_version() {
+        let labeled = LabeledVersion::from(Version::new_base_version(1, 2));
+
+        assert!(labeled.pre.is_empty());
+        assert!(labeled.build.is_empty());
+        assert_eq!(labeled.to_string(), "1.2");
+    }
+
+    #[yare::parameterized(
+        empty = { "", LabelError::EmptyIdentifier },
+        leading_zero = { "alpha.01", LabelError::LeadingZero },
+        invalid = { "alpha+1", LabelError::InvalidCharacter { got: b'+' } },
+    )]
+    fn prerelease_rejected(input: &str, expected: LabelError) {
+        assert_eq!(Prerelease::new(input).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn build_metadata_leading_zero() {
+        assert_eq!(BuildMetadata::new("007").unwrap().as_str(), "007");
+    }
+}
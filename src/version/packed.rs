@@ -0,0 +1,428 @@
+use crate::{BaseVersion, Component, FullVersion};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// A [`FullVersion`] which is packed into a single `u64`, where the `major`, `minor` and `patch`
+/// components take `MAJOR`, `MINOR` and `PATCH` bits respectively.
+///
+/// A [`FullVersion`] takes 24 bytes, and a [`Version`] 32 bytes, while a packed version takes
+/// 8 bytes, at the cost of a smaller range for each component. A common layout is
+/// `PackedFullVersion<16, 24, 24>`, which fits majors up to `65535`, and minors and patches up
+/// to `16777215`.
+///
+/// The components are stored from the most to the least significant bits, so the packed
+/// integer, as returned by [`PackedFullVersion::to_bits`], sorts like the version.
+///
+/// The bits of the components may not add up to more than 64; using such a layout fails to
+/// compile:
+///
+/// ```compile_fail
+/// use version_number::{FullVersion, PackedFullVersion};
+/// use std::convert::TryFrom;
+///
+/// let packed = PackedFullVersion::<32, 32, 32>::try_from(FullVersion::new(1, 2, 3));
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use version_number::{Component, FullVersion, PackError, PackedFullVersion};
+/// use std::convert::TryFrom;
+///
+/// type Packed = PackedFullVersion<16, 24, 24>;
+///
+/// let packed = Packed::try_from(FullVersion::new(1, 70, 0)).unwrap();
+///
+/// assert_eq!(packed.to_full_version(), FullVersion::new(1, 70, 0));
+/// assert!(packed < Packed::new(1, 71, 0).unwrap());
+/// assert!(packed.to_bits() < Packed::new(1, 71, 0).unwrap().to_bits());
+///
+/// assert_eq!(
+///     Packed::new(65536, 0, 0),
+///     Err(PackError::Overflow { component: Component::Major, max: 65535 }),
+/// );
+/// ```
+///
+/// [`FullVersion`]: crate::FullVersion
+/// [`Version`]: crate::Version
+#[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PackedFullVersion<const MAJOR: u32, const MINOR: u32, const PATCH: u32> {
+    bits: u64,
+}
+
+impl<const MAJOR: u32, const MINOR: u32, const PATCH: u32> PackedFullVersion<MAJOR, MINOR, PATCH> {
+    /// The largest `major` component which fits in the layout.
+    pub const MAX_MAJOR: u64 = mask(MAJOR);
+    /// The largest `minor` component which fits in the layout.
+    pub const MAX_MINOR: u64 = mask(MINOR);
+    /// The largest `patch` component which fits in the layout.
+    pub const MAX_PATCH: u64 = mask(PATCH);
+
+    // The bits which may be set. Evaluating this constant fails to compile if the components
+    // don't fit in a `u64`.
+    const USED: u64 = mask(u64::BITS - (u64::BITS - (MAJOR + MINOR + PATCH)));
+
+    /// Pack a version with the given `major`, `minor` and `patch` components.
+    ///
+    /// Returns a [`PackError`] if a component does not fit in the layout.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Result<Self, PackError> {
+        let major = check(major, Self::MAX_MAJOR, Component::Major)?;
+        let minor = check(minor, Self::MAX_MINOR, Component::Minor)?;
+        let patch = check(patch, Self::MAX_PATCH, Component::Patch)?;
+
+        // Masking with the used bits also rejects a layout which doesn't fit, at compile time.
+        Ok(Self {
+            bits: (shl(major, MINOR + PATCH) | shl(minor, PATCH) | patch) & Self::USED,
+        })
+    }
+
+    /// Unpack a version from the packed integer, as returned by [`PackedFullVersion::to_bits`].
+    ///
+    /// Returns `None` if bits are set which are not used by the layout.
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        if bits & !Self::USED == 0 {
+            Some(Self { bits })
+        } else {
+            None
+        }
+    }
+
+    /// The packed integer, which sorts like the version.
+    pub fn to_bits(self) -> u64 {
+        self.bits
+    }
+
+    /// Returns the `major` version component.
+    pub fn major(self) -> u64 {
+        shr(self.bits, MINOR + PATCH) & Self::MAX_MAJOR
+    }
+
+    /// Returns the `minor` version component.
+    pub fn minor(self) -> u64 {
+        shr(self.bits, PATCH) & Self::MAX_MINOR
+    }
+
+    /// Returns the `patch` version component.
+    pub fn patch(self) -> u64 {
+        self.bits & Self::MAX_PATCH
+    }
+
+    /// Unpack the version.
+    pub fn to_full_version(self) -> FullVersion {
+        FullVersion::new(self.major(), self.minor(), self.patch())
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32, const PATCH: u32> TryFrom<FullVersion>
+    for PackedFullVersion<MAJOR, MINOR, PATCH>
+{
+    type Error = PackError;
+
+    fn try_from(version: FullVersion) -> Result<Self, Self::Error> {
+        Self::new(version.major, version.minor, version.patch)
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32, const PATCH: u32>
+    From<PackedFullVersion<MAJOR, MINOR, PATCH>> for FullVersion
+{
+    fn from(packed: PackedFullVersion<MAJOR, MINOR, PATCH>) -> Self {
+        packed.to_full_version()
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32, const PATCH: u32> fmt::Debug
+    for PackedFullVersion<MAJOR, MINOR, PATCH>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedFullVersion")
+            .field("major", &self.major())
+            .field("minor", &self.minor())
+            .field("patch", &self.patch())
+            .finish()
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32, const PATCH: u32> fmt::Display
+    for PackedFullVersion<MAJOR, MINOR, PATCH>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_full_version(), f)
+    }
+}
+
+/// A [`BaseVersion`] which is packed into a single `u64`, where the `major` and `minor`
+/// components take `MAJOR` and `MINOR` bits respectively.
+///
+/// Like a [`PackedFullVersion`], the packed integer sorts like the version, and the bits of
+/// the components may not add up to more than 64.
+///
+/// # Example
+///
+/// ```
+/// use version_number::{BaseVersion, PackedBaseVersion};
+/// use std::convert::TryFrom;
+///
+/// let packed = PackedBaseVersion::<32, 32>::try_from(BaseVersion::new(1, 70)).unwrap();
+///
+/// assert_eq!(packed.to_bits(), 1 << 32 | 70);
+/// assert_eq!(packed.to_base_version(), BaseVersion::new(1, 70));
+/// ```
+///
+/// [`BaseVersion`]: crate::BaseVersion
+#[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PackedBaseVersion<const MAJOR: u32, const MINOR: u32> {
+    bits: u64,
+}
+
+impl<const MAJOR: u32, const MINOR: u32> PackedBaseVersion<MAJOR, MINOR> {
+    /// The largest `major` component which fits in the layout.
+    pub const MAX_MAJOR: u64 = mask(MAJOR);
+    /// The largest `minor` component which fits in the layout.
+    pub const MAX_MINOR: u64 = mask(MINOR);
+
+    // The bits which may be set. Evaluating this constant fails to compile if the components
+    // don't fit in a `u64`.
+    const USED: u64 = mask(u64::BITS - (u64::BITS - (MAJOR + MINOR)));
+
+    /// Pack a version with the given `major` and `minor` components.
+    ///
+    /// Returns a [`PackError`] if a component does not fit in the layout.
+    pub fn new(major: u64, minor: u64) -> Result<Self, PackError> {
+        let major = check(major, Self::MAX_MAJOR, Component::Major)?;
+        let minor = check(minor, Self::MAX_MINOR, Component::Minor)?;
+
+        // Masking with the used bits also rejects a layout which doesn't fit, at compile time.
+        Ok(Self {
+            bits: (shl(major, MINOR) | minor) & Self::USED,
+        })
+    }
+
+    /// Unpack a version from the packed integer, as returned by [`PackedBaseVersion::to_bits`].
+    ///
+    /// Returns `None` if bits are set which are not used by the layout.
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        if bits & !Self::USED == 0 {
+            Some(Self { bits })
+        } else {
+            None
+        }
+    }
+
+    /// The packed integer, which sorts like the version.
+    pub fn to_bits(self) -> u64 {
+        self.bits
+    }
+
+    /// Returns the `major` version component.
+    pub fn major(self) -> u64 {
+        shr(self.bits, MINOR) & Self::MAX_MAJOR
+    }
+
+    /// Returns the `minor` version component.
+    pub fn minor(self) -> u64 {
+        self.bits & Self::MAX_MINOR
+    }
+
+    /// Unpack the version.
+    pub fn to_base_version(self) -> BaseVersion {
+        BaseVersion::new(self.major(), self.minor())
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32> TryFrom<BaseVersion> for PackedBaseVersion<MAJOR, MINOR> {
+    type Error = PackError;
+
+    fn try_from(version: BaseVersion) -> Result<Self, Self::Error> {
+        Self::new(version.major, version.minor)
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32> From<PackedBaseVersion<MAJOR, MINOR>> for BaseVersion {
+    fn from(packed: PackedBaseVersion<MAJOR, MINOR>) -> Self {
+        packed.to_base_version()
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32> fmt::Debug for PackedBaseVersion<MAJOR, MINOR> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PackedBaseVersion")
+            .field("major", &self.major())
+            .field("minor", &self.minor())
+            .finish()
+    }
+}
+
+impl<const MAJOR: u32, const MINOR: u32> fmt::Display for PackedBaseVersion<MAJOR, MINOR> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_base_version(), f)
+    }
+}
+
+/// An error which may be returned when packing a version.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PackError {
+    /// This error variant is returned if a component is larger than the largest value which
+    /// fits in the bits the layout reserves for it.
+    Overflow {
+        /// The component which does not fit.
+        component: Component,
+        /// The largest value which fits in the layout.
+        max: u64,
+    },
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow { component, max } => write!(
+                f,
+                "Overflow: Unable to pack the {} component, since it is larger than the maximum supported by the layout (max={})",
+                component, max
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackError {}
+
+// The largest value which fits in the given number of bits.
+const fn mask(bits: u32) -> u64 {
+    if bits == 0 {
+        0
+    } else {
+        u64::MAX >> (u64::BITS - bits)
+    }
+}
+
+// Shifts which result in `0` when all bits are shifted out, instead of overflowing.
+fn shl(value: u64, bits: u32) -> u64 {
+    value.checked_shl(bits).unwrap_or(0)
+}
+
+fn shr(value: u64, bits: u32) -> u64 {
+    value.checked_shr(bits).unwrap_or(0)
+}
+
+fn check(value: u64, max: u64, component: Component) -> Result<u64, PackError> {
+    if value <= max {
+        Ok(value)
+    } else {
+        Err(PackError::Overflow { component, max })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+    use std::convert::TryInto;
+
+    type Packed = PackedFullVersion<16, 24, 24>;
+
+    #[yare::parameterized(
+        zeros = { FullVersion::new(0, 0, 0) },
+        rust = { FullVersion::new(1, 70, 0) },
+        max = { FullVersion::new(65535, 16777215, 16777215) },
+    )]
+    fn round_trip(version: FullVersion) {
+        let packed = Packed::try_from(version).unwrap();
+
+        assert_eq!(FullVersion::from(packed), version);
+        assert_eq!(packed.to_string(), version.to_string());
+        assert_eq!(Packed::from_bits(packed.to_bits()), Some(packed));
+    }
+
+    #[yare::parameterized(
+        major = { FullVersion::new(65536, 0, 0), Component::Major, 65535 },
+        minor = { FullVersion::new(0, 16777216, 0), Component::Minor, 16777215 },
+        patch = { FullVersion::new(0, 0, u64::MAX), Component::Patch, 16777215 },
+    )]
+    fn overflow(version: FullVersion, component: Component, max: u64) {
+        let result: Result<Packed, _> = version.try_into();
+
+        assert_eq!(result, Err(PackError::Overflow { component, max }));
+    }
+
+    #[yare::parameterized(
+        patch = { (1, 2, 3), (1, 2, 4) },
+        minor = { (1, 2, 16777215), (1, 3, 0) },
+        major = { (1, 16777215, 16777215), (2, 0, 0) },
+    )]
+    fn order_preserving(lhs: (u64, u64, u64), rhs: (u64, u64, u64)) {
+        let lhs = Packed::try_from(FullVersion::from(lhs)).unwrap();
+        let rhs = Packed::try_from(FullVersion::from(rhs)).unwrap();
+
+        assert_eq!(lhs.cmp(&rhs), Ordering::Less);
+        assert_eq!(lhs.to_bits().cmp(&rhs.to_bits()), Ordering::Less);
+        assert_eq!(
+            lhs.to_full_version().cmp(&rhs.to_full_version()),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn from_bits_rejects_unused_bits() {
+        assert!(PackedFullVersion::<8, 8, 8>::from_bits(1 << 24).is_none());
+        assert!(PackedFullVersion::<8, 8, 8>::from_bits((1 << 24) - 1).is_some());
+    }
+
+    #[test]
+    fn whole_u64_component() {
+        let packed = PackedFullVersion::<64, 0, 0>::new(u64::MAX, 0, 0).unwrap();
+
+        assert_eq!(packed.to_full_version(), FullVersion::new(u64::MAX, 0, 0));
+        assert_eq!(
+            PackedFullVersion::<64, 0, 0>::new(0, 1, 0),
+            Err(PackError::Overflow {
+                component: Component::Minor,
+                max: 0
+            })
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let packed = Packed::new(1, 2, 3).unwrap();
+
+        assert_eq!(
+            format!("{:?}", packed),
+            "PackedFullVersion { major: 1, minor: 2, patch: 3 }"
+        );
+    }
+
+    #[yare::parameterized(
+        zeros = { BaseVersion::new(0, 0) },
+        rust = { BaseVersion::new(1, 70) },
+        max = { BaseVersion::new(u64::from(u32::MAX), u64::from(u32::MAX)) },
+    )]
+    fn base_round_trip(version: BaseVersion) {
+        let packed = PackedBaseVersion::<32, 32>::try_from(version).unwrap();
+
+        assert_eq!(BaseVersion::from(packed), version);
+        assert_eq!(packed.to_string(), version.to_string());
+    }
+
+    #[test]
+    fn base_overflow() {
+        let result = PackedBaseVersion::<32, 32>::new(0, u64::from(u32::MAX) + 1);
+
+        assert_eq!(
+            result,
+            Err(PackError::Overflow {
+                component: Component::Minor,
+                max: u64::from(u32::MAX)
+            })
+        );
+    }
+
+    #[test]
+    fn base_order_preserving() {
+        let lhs = PackedBaseVersion::<16, 16>::new(1, 65535).unwrap();
+        let rhs = PackedBaseVersion::<16, 16>::new(2, 0).unwrap();
+
+        assert!(lhs < rhs);
+        assert!(lhs.to_bits() < rhs.to_bits());
+    }
+}
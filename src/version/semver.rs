@@ -0,0 +1,272 @@
+//! Conversions between the version types of this crate and the types of the `semver` crate.
+//!
+//! Requires the `semver` feature to be enabled.
+
+use crate::{BaseVersion, FullVersion, Version};
+use std::convert::TryFrom;
+
+/// An error which is returned when a [`semver::Version`] can't be converted to a
+/// [`FullVersion`] or [`Version`], because it has labels which are not supported by these
+/// version types.
+///
+/// When both a pre-release and build metadata are present, the pre-release is reported.
+///
+/// Requires the `semver` feature to be enabled.
+///
+/// [`semver::Version`]: https://docs.rs/semver/1/semver/struct.Version.html
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SemverConversionError {
+    /// The semver version has a pre-release label, such as the `beta.3` in `1.70.0-beta.3`.
+    #[error("Unable to convert a semver version with pre-release label '{0}'")]
+    PreRelease(semver::Prerelease),
+
+    /// The semver version has build metadata, such as the `build.5` in `1.2.3+build.5`.
+    #[error("Unable to convert a semver version with build metadata '{0}'")]
+    BuildMetadata(semver::BuildMetadata),
+}
+
+impl From<FullVersion> for semver::Version {
+    /// Convert the given [`FullVersion`] to a [`semver::Version`].
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use version_number::FullVersion;
+    ///
+    /// let version = FullVersion::new(1, 2, 3);
+    /// let converted: semver::Version = version.into();
+    ///
+    /// assert_eq!(converted, semver::Version::new(1, 2, 3));
+    /// ```
+    ///
+    /// [`FullVersion`]: crate::FullVersion
+    /// [`semver::Version`]: https://docs.rs/semver/1/semver/struct.Version.html
+    fn from(version: FullVersion) -> Self {
+        semver::Version::new(version.major, version.minor, version.patch)
+    }
+}
+
+impl TryFrom<semver::Version> for FullVersion {
+    type Error = SemverConversionError;
+
+    /// Convert the given [`semver::Version`] to a [`FullVersion`].
+    ///
+    /// Fails if the semver version has a pre-release label or build metadata.
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::convert::TryFrom;
+    /// # use version_number::FullVersion;
+    ///
+    /// let version = semver::Version::new(1, 2, 3);
+    /// assert_eq!(FullVersion::try_from(version).unwrap(), FullVersion::new(1, 2, 3));
+    ///
+    /// let version = semver::Version::parse("1.2.3-alpha").unwrap();
+    /// assert!(FullVersion::try_from(version).is_err());
+    /// ```
+    ///
+    /// [`semver::Version`]: https://docs.rs/semver/1/semver/struct.Version.html
+    fn try_from(version: semver::Version) -> Result<Self, Self::Error> {
+        if !version.pre.is_empty() {
+            return Err(SemverConversionError::PreRelease(version.pre));
+        }
+
+        if !version.build.is_empty() {
+            return Err(SemverConversionError::BuildMetadata(version.build));
+        }
+
+        Ok(FullVersion::new(
+            version.major,
+            version.minor,
+            version.patch,
+        ))
+    }
+}
+
+impl TryFrom<semver::Version> for Version {
+    type Error = SemverConversionError;
+
+    /// Convert the given [`semver::Version`] to a three component [`Version::Full`].
+    ///
+    /// Fails if the semver version has a pre-release label or build metadata.
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// [`semver::Version`]: https://docs.rs/semver/1/semver/struct.Version.html
+    fn try_from(version: semver::Version) -> Result<Self, Self::Error> {
+        FullVersion::try_from(version).map(Version::Full)
+    }
+}
+
+impl From<BaseVersion> for semver::Comparator {
+    /// Convert the given [`BaseVersion`] to a tilde [`semver::Comparator`], i.e. `1.2` is
+    /// converted to `~1.2`, which matches any version `1.2.x`.
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use version_number::BaseVersion;
+    ///
+    /// let comparator = semver::Comparator::from(BaseVersion::new(1, 2));
+    ///
+    /// assert_eq!(comparator, semver::Comparator::parse("~1.2").unwrap());
+    /// ```
+    ///
+    /// [`semver::Comparator`]: https://docs.rs/semver/1/semver/struct.Comparator.html
+    fn from(version: BaseVersion) -> Self {
+        semver::Comparator {
+            op: semver::Op::Tilde,
+            major: version.major,
+            minor: Some(version.minor),
+            patch: None,
+            pre: semver::Prerelease::EMPTY,
+        }
+    }
+}
+
+impl From<BaseVersion> for semver::VersionReq {
+    /// Convert the given [`BaseVersion`] to a [`semver::VersionReq`], which consists of a single
+    /// tilde comparator, i.e. `1.2` is converted to `~1.2`.
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// [`semver::VersionReq`]: https://docs.rs/semver/1/semver/struct.VersionReq.html
+    fn from(version: BaseVersion) -> Self {
+        semver::VersionReq {
+            comparators: vec![semver::Comparator::from(version)],
+        }
+    }
+}
+
+impl BaseVersion {
+    /// Check whether this version matches the given [`semver::VersionReq`].
+    ///
+    /// The version is matched as if its `patch` component were `0`, i.e. `1.2` is matched as
+    /// `1.2.0`.
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use version_number::BaseVersion;
+    ///
+    /// let requirement = semver::VersionReq::parse(">=1.56").unwrap();
+    ///
+    /// assert!(BaseVersion::new(1, 56).matches_semver_req(&requirement));
+    /// assert!(!BaseVersion::new(1, 55).matches_semver_req(&requirement));
+    /// ```
+    ///
+    /// [`semver::VersionReq`]: https://docs.rs/semver/1/semver/struct.VersionReq.html
+    pub fn matches_semver_req(&self, requirement: &semver::VersionReq) -> bool {
+        self.to_full_version_lossy().matches_semver_req(requirement)
+    }
+}
+
+impl FullVersion {
+    /// Check whether this version matches the given [`semver::VersionReq`].
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// [`semver::VersionReq`]: https://docs.rs/semver/1/semver/struct.VersionReq.html
+    pub fn matches_semver_req(&self, requirement: &semver::VersionReq) -> bool {
+        requirement.matches(&semver::Version::from(*self))
+    }
+}
+
+impl Version {
+    /// Check whether this version matches the given [`semver::VersionReq`].
+    ///
+    /// A two component [`Version::Base`] is matched as if its `patch` component were `0`.
+    ///
+    /// Requires the `semver` feature to be enabled.
+    ///
+    /// [`semver::VersionReq`]: https://docs.rs/semver/1/semver/struct.VersionReq.html
+    pub fn matches_semver_req(&self, requirement: &semver::VersionReq) -> bool {
+        match self {
+            Version::Base(base) => base.matches_semver_req(requirement),
+            Version::Full(full) => full.matches_semver_req(requirement),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SemverConversionError;
+    use crate::{BaseVersion, FullVersion, Version};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn from_full_version() {
+        let converted = semver::Version::from(FullVersion::new(1, 2, 3));
+
+        assert_eq!(converted, semver::Version::new(1, 2, 3));
+    }
+
+    #[test]
+    fn try_from_semver_version() {
+        let version = semver::Version::new(1, 2, 3);
+
+        assert_eq!(
+            FullVersion::try_from(version.clone()).unwrap(),
+            FullVersion::new(1, 2, 3)
+        );
+        assert_eq!(
+            Version::try_from(version).unwrap(),
+            Version::new_full_version(1, 2, 3)
+        );
+    }
+
+    #[yare::parameterized(
+        pre_release = { "1.70.0-beta.3", "beta.3" },
+        pre_release_and_build = { "1.70.0-beta.3+build.5", "beta.3" },
+    )]
+    fn try_from_rejects_pre_release(input: &str, expected: &str) {
+        let version = semver::Version::parse(input).unwrap();
+        let err = Version::try_from(version).unwrap_err();
+
+        assert_eq!(
+            err,
+            SemverConversionError::PreRelease(semver::Prerelease::new(expected).unwrap())
+        );
+    }
+
+    #[test]
+    fn try_from_rejects_build_metadata() {
+        let version = semver::Version::parse("1.2.3+build.5").unwrap();
+        let err = FullVersion::try_from(version).unwrap_err();
+
+        assert_eq!(
+            err,
+            SemverConversionError::BuildMetadata(semver::BuildMetadata::new("build.5").unwrap())
+        );
+    }
+
+    #[test]
+    fn base_version_to_version_req() {
+        let requirement = semver::VersionReq::from(BaseVersion::new(1, 2));
+
+        assert_eq!(requirement, semver::VersionReq::parse("~1.2").unwrap());
+        assert!(requirement.matches(&semver::Version::new(1, 2, 99)));
+        assert!(!requirement.matches(&semver::Version::new(1, 3, 0)));
+    }
+
+    #[yare::parameterized(
+        base_match = { Version::new_base_version(1, 56), ">=1.56, <1.70", true },
+        base_patch_zero = { Version::new_base_version(1, 2), ">=1.2.1", false },
+        full_match = { Version::new_full_version(1, 69, 1), ">=1.56, <1.70", true },
+        full_no_match = { Version::new_full_version(1, 70, 0), ">=1.56, <1.70", false },
+    )]
+    fn matches_semver_req(version: Version, requirement: &str, expected: bool) {
+        let requirement = semver::VersionReq::parse(requirement).unwrap();
+
+        assert_eq!(version.matches_semver_req(&requirement), expected);
+    }
+}
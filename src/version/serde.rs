@@ -0,0 +1,323 @@
+//! Implementations of [`Serialize`] and [`Deserialize`] for the version types.
+//!
+//! Versions are serialized as a string, such as `"1.2.3"`. When deserializing, a string is
+//! parsed with the same parsers as used by the respective `parse` methods. In addition,
+//! self-describing formats may provide a version as a sequence of components, like `[1, 2, 3]`,
+//! or as a map of components, like `{ "major": 1, "minor": 2, "patch": 3 }`.
+
+use crate::{BaseVersion, FullVersion, Version};
+use serde::de::{self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+impl Serialize for BaseVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for FullVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BaseVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FullVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+// Formats which are not self-describing can't deserialize "any" value, but since we serialize
+// versions as a string, we can still deserialize from a string.
+fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromComponents,
+{
+    let visitor = ComponentsVisitor(PhantomData);
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+/// A version type which can be constructed from its string representation, or from its
+/// separate components.
+trait FromComponents: Sized {
+    const EXPECTING: &'static str;
+    const FIELDS: &'static [&'static str];
+
+    fn parse<E: de::Error>(input: &str) -> Result<Self, E>;
+
+    /// Returns `None` if the number of components does not fit the version type.
+    fn from_components(major: u64, minor: u64, patch: Option<u64>) -> Option<Self>;
+}
+
+impl FromComponents for BaseVersion {
+    const EXPECTING: &'static str = "a two component 'major.minor' version";
+    const FIELDS: &'static [&'static str] = &["major", "minor"];
+
+    fn parse<E: de::Error>(input: &str) -> Result<Self, E> {
+        BaseVersion::parse(input).map_err(E::custom)
+    }
+
+    fn from_components(major: u64, minor: u64, patch: Option<u64>) -> Option<Self> {
+        match patch {
+            Some(_) => None,
+            None => Some(BaseVersion::new(major, minor)),
+        }
+    }
+}
+
+impl FromComponents for FullVersion {
+    const EXPECTING: &'static str = "a three component 'major.minor.patch' version";
+    const FIELDS: &'static [&'static str] = &["major", "minor", "patch"];
+
+    fn parse<E: de::Error>(input: &str) -> Result<Self, E> {
+        FullVersion::parse(input).map_err(E::custom)
+    }
+
+    fn from_components(major: u64, minor: u64, patch: Option<u64>) -> Option<Self> {
+        patch.map(|patch| FullVersion::new(major, minor, patch))
+    }
+}
+
+impl FromComponents for Version {
+    const EXPECTING: &'static str =
+        "a two component 'major.minor', or three component 'major.minor.patch' version";
+    const FIELDS: &'static [&'static str] = &["major", "minor", "patch"];
+
+    fn parse<E: de::Error>(input: &str) -> Result<Self, E> {
+        Version::parse(input).map_err(E::custom)
+    }
+
+    fn from_components(major: u64, minor: u64, patch: Option<u64>) -> Option<Self> {
+        Some(match patch {
+            Some(patch) => Version::new_full_version(major, minor, patch),
+            None => Version::new_base_version(major, minor),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Major,
+    Minor,
+    Patch,
+}
+
+struct ComponentsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromComponents> Visitor<'de> for ComponentsVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::parse(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let major = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let minor = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let patch = seq.next_element()?;
+
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(4, &self));
+        }
+
+        T::from_components(major, minor, patch).ok_or_else(|| {
+            let len = if patch.is_some() { 3 } else { 2 };
+            de::Error::invalid_length(len, &self)
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut major = None;
+        let mut minor = None;
+        let mut patch = None;
+
+        while let Some(field) = map.next_key()? {
+            let (name, component) = match field {
+                Field::Major => ("major", &mut major),
+                Field::Minor => ("minor", &mut minor),
+                Field::Patch => ("patch", &mut patch),
+            };
+
+            if component.is_some() {
+                return Err(de::Error::duplicate_field(name));
+            }
+
+            *component = Some(map.next_value()?);
+        }
+
+        let major = major.ok_or_else(|| de::Error::missing_field("major"))?;
+        let minor = minor.ok_or_else(|| de::Error::missing_field("minor"))?;
+
+        T::from_components(major, minor, patch).ok_or_else(|| match patch {
+            Some(_) => de::Error::unknown_field("patch", T::FIELDS),
+            None => de::Error::missing_field("patch"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BaseVersion, FullVersion, Variant, Version};
+
+    #[yare::parameterized(
+        base = { Version::new_base_version(1, 2), r#""1.2""# },
+        full = { Version::new_full_version(1, 2, 3), r#""1.2.3""# },
+    )]
+    fn serialize_version(version: Version, expected: &str) {
+        assert_eq!(serde_json::to_string(&version).unwrap(), expected);
+    }
+
+    #[test]
+    fn serialize_base_and_full() {
+        assert_eq!(
+            serde_json::to_string(&BaseVersion::new(1, 2)).unwrap(),
+            r#""1.2""#
+        );
+        assert_eq!(
+            serde_json::to_string(&FullVersion::new(1, 2, 3)).unwrap(),
+            r#""1.2.3""#
+        );
+    }
+
+    #[yare::parameterized(
+        base_str = { r#""1.2""#, Version::new_base_version(1, 2) },
+        full_str = { r#""1.2.3""#, Version::new_full_version(1, 2, 3) },
+        base_seq = { "[1, 2]", Version::new_base_version(1, 2) },
+        full_seq = { "[1, 2, 3]", Version::new_full_version(1, 2, 3) },
+        base_map = { r#"{ "minor": 2, "major": 1 }"#, Version::new_base_version(1, 2) },
+        full_map = { r#"{ "major": 1, "minor": 2, "patch": 3 }"#, Version::new_full_version(1, 2, 3) },
+    )]
+    fn deserialize_version(input: &str, expected: Version) {
+        let version: Version = serde_json::from_str(input).unwrap();
+
+        assert_eq!(version, expected);
+    }
+
+    #[yare::parameterized(
+        str = { r#""1.2""# },
+        seq = { "[1, 2]" },
+        map = { r#"{ "major": 1, "minor": 2 }"# },
+    )]
+    fn deserialize_base(input: &str) {
+        let version: BaseVersion = serde_json::from_str(input).unwrap();
+
+        assert_eq!(version, BaseVersion::new(1, 2));
+    }
+
+    #[yare::parameterized(
+        str = { r#""1.2.3""# },
+        seq = { "[1, 2, 3]" },
+        map = { r#"{ "major": 1, "minor": 2, "patch": 3 }"# },
+    )]
+    fn deserialize_full(input: &str) {
+        let version: FullVersion = serde_json::from_str(input).unwrap();
+
+        assert_eq!(version, FullVersion::new(1, 2, 3));
+    }
+
+    #[yare::parameterized(
+        base = { Version::new_base_version(0, u64::MAX) },
+        full = { Version::new_full_version(u64::MAX, 0, 1) },
+    )]
+    fn round_trip(version: Version) {
+        let serialized = serde_json::to_string(&version).unwrap();
+        let deserialized: Version = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, version);
+    }
+
+    #[test]
+    fn error_carries_parser_error() {
+        let err = serde_json::from_str::<Version>(r#""1.02""#).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("Number may not start with a leading zero"));
+    }
+
+    #[yare::parameterized(
+        too_few = { "[1]", "invalid length 1" },
+        too_many = { "[1, 2, 3, 4]", "invalid length 4" },
+        missing_minor = { r#"{ "major": 1 }"#, "missing field `minor`" },
+        duplicate = { r#"{ "major": 1, "major": 1 }"#, "duplicate field `major`" },
+        unknown = { r#"{ "major": 1, "minor": 2, "build": 3 }"#, "unknown field `build`" },
+        negative = { "[-1, 2]", "invalid value: integer `-1`" },
+    )]
+    fn deserialize_version_error(input: &str, expected: &str) {
+        let err = serde_json::from_str::<Version>(input).unwrap_err();
+
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+
+    #[yare::parameterized(
+        base_with_patch_seq = { "[1, 2, 3]", "invalid length 3" },
+        base_with_patch_map = { r#"{ "major": 1, "minor": 2, "patch": 3 }"#, "unknown field `patch`" },
+        base_with_patch_str = { r#""1.2.3""#, "Expected 0-9 or EOI, but got '.' (at 3..4)" },
+    )]
+    fn deserialize_base_error(input: &str, expected: &str) {
+        let err = serde_json::from_str::<BaseVersion>(input).unwrap_err();
+
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+
+    #[yare::parameterized(
+        full_without_patch_seq = { "[1, 2]", "invalid length 2" },
+        full_without_patch_map = { r#"{ "major": 1, "minor": 2 }"#, "missing field `patch`" },
+        full_without_patch_str = { r#""1.2""#, "Expected 0-9 or '.', but got 'EOI' (at 3..3)" },
+    )]
+    fn deserialize_full_error(input: &str, expected: &str) {
+        let err = serde_json::from_str::<FullVersion>(input).unwrap_err();
+
+        assert!(err.to_string().starts_with(expected), "{}", err);
+    }
+
+    #[yare::parameterized(
+        base = { Variant::Base, r#""Base""# },
+        full = { Variant::Full, r#""Full""# },
+    )]
+    fn variant(variant: Variant, expected: &str) {
+        let serialized = serde_json::to_string(&variant).unwrap();
+        assert_eq!(serialized, expected);
+
+        let deserialized: Variant = serde_json::from_str(&serialized).unwrap();
+        assert!(matches!(
+            (variant, deserialized),
+            (Variant::Base, Variant::Base) | (Variant::Full, Variant::Full)
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+//! Combinators for [`winnow`], to parse version numbers as part of a larger grammar.
+//!
+//! Requires the `winnow` feature to be enabled.
+//!
+//! The combinators accept any stream which implements [`Stream`] and [`AsBStr`], such as `&str`
+//! and `&[u8]`, and share the component parsing of the [`modular`] parser, so leading zeros and
+//! components which overflow a `u64` are rejected.
+//!
+//! Like the `parse_*_prefix` methods of the parsers, the combinators do not expect the
+//! end-of-input after the version. [`version`] is greedy: it parses the patch component if the
+//! minor component is followed by a separator and a digit.
+//!
+//! # Errors
+//!
+//! Errors are created at the position of the offending separator or component. When a component
+//! has a leading zero or overflows, the [`NumberError`] is passed to
+//! [`FromExternalError::from_external_error`]. With an [`ErrMode`], all errors are recoverable
+//! backtracks, which may be turned into failures with `winnow::combinator::cut_err`.
+//!
+//! # Example
+//!
+//! ```
+//! use version_number::Version;
+//! use winnow::combinator::preceded;
+//! use winnow::error::ContextError;
+//! use winnow::Parser;
+//!
+//! let mut input = "foo-1.2.3.tar.gz";
+//! let version = preceded("foo-", version_number::winnow::version::<_, ContextError>)
+//!     .parse_next(&mut input)
+//!     .unwrap();
+//!
+//! assert_eq!(version, Version::new_full_version(1, 2, 3));
+//! assert_eq!(input, ".tar.gz");
+//! ```
+//!
+//! [`winnow`]: https://docs.rs/winnow
+//! [`modular`]: crate::parsers::modular
+//! [`NumberError`]: crate::parsers::modular::NumberError
+//! [`ErrMode`]: ::winnow::error::ErrMode
+
+use crate::parsers::modular::component::{parse_component_prefix, starts_with_patch};
+use crate::parsers::modular::{ModularParserError, NumberError};
+use crate::{BaseVersion, FullVersion, Version};
+use ::winnow::error::{FromExternalError, ParserError};
+use ::winnow::stream::{AsBStr, Stream};
+
+/// Parse a single version component, i.e. a number without leading zeros.
+pub fn component<I, E>(input: &mut I) -> Result<u64, E>
+where
+    I: Stream + AsBStr,
+    E: ParserError<I> + FromExternalError<I, NumberError>,
+{
+    match parse_component_prefix(input.as_bstr()) {
+        Ok((value, consumed)) => {
+            input.next_slice(consumed);
+            Ok(value)
+        }
+        Err(ModularParserError::NumberError { error, .. }) => {
+            Err(E::from_external_error(input, error))
+        }
+        Err(_) => Err(E::from_input(input)),
+    }
+}
+
+/// Parse a two component `major.minor` [`BaseVersion`].
+///
+/// Parsing stops after the minor component, so for an input like `1.2.3`, the remaining input
+/// is `.3`.
+pub fn base_version<I, E>(input: &mut I) -> Result<BaseVersion, E>
+where
+    I: Stream + AsBStr,
+    E: ParserError<I> + FromExternalError<I, NumberError>,
+{
+    let major = component(input)?;
+    separator(input)?;
+    let minor = component(input)?;
+
+    Ok(BaseVersion::new(major, minor))
+}
+
+/// Parse a three component `major.minor.patch` [`FullVersion`].
+pub fn full_version<I, E>(input: &mut I) -> Result<FullVersion, E>
+where
+    I: Stream + AsBStr,
+    E: ParserError<I> + FromExternalError<I, NumberError>,
+{
+    let base = base_version(input)?;
+    separator(input)?;
+    let patch = component(input)?;
+
+    Ok(FullVersion::new(base.major, base.minor, patch))
+}
+
+/// Parse a two component [`Version::Base`], or a three component [`Version::Full`].
+///
+/// The patch component is parsed if the minor component is followed by a separator and a digit,
+/// so for an input like `1.2.x`, a base version is parsed, and the remaining input is `.x`.
+pub fn version<I, E>(input: &mut I) -> Result<Version, E>
+where
+    I: Stream + AsBStr,
+    E: ParserError<I> + FromExternalError<I, NumberError>,
+{
+    let base = base_version(input)?;
+
+    if !starts_with_patch(input.as_bstr()) {
+        return Ok(Version::Base(base));
+    }
+
+    separator(input)?;
+    let patch = component(input)?;
+
+    Ok(Version::Full(FullVersion::new(
+        base.major, base.minor, patch,
+    )))
+}
+
+// Parse the `.` separator between two components.
+fn separator<I, E>(input: &mut I) -> Result<(), E>
+where
+    I: Stream + AsBStr,
+    E: ParserError<I>,
+{
+    match input.as_bstr().first() {
+        Some(b'.') => {
+            input.next_slice(1);
+            Ok(())
+        }
+        _ => Err(E::from_input(input)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::winnow::error::{ErrMode, InputError};
+    use ::winnow::Parser;
+
+    #[yare::parameterized(
+        base = { "1.2", Version::new_base_version(1, 2), "" },
+        full = { "1.2.3", Version::new_full_version(1, 2, 3), "" },
+        archive = { "1.2.3.tar.gz", Version::new_full_version(1, 2, 3), ".tar.gz" },
+        rustc = { "1.70.0 (90c541806 2023-05-31)", Version::new_full_version(1, 70, 0), " (90c541806 2023-05-31)" },
+        dot_not_followed_by_digit = { "1.2.x", Version::new_base_version(1, 2), ".x" },
+        label = { "1.2-beta", Version::new_base_version(1, 2), "-beta" },
+    )]
+    fn accepted(input: &str, expected: Version, rest: &str) {
+        let mut input = input;
+        let result: Result<Version, InputError<&str>> = version(&mut input);
+
+        assert_eq!(result, Ok(expected));
+        assert_eq!(input, rest);
+    }
+
+    #[yare::parameterized(
+        empty = { "", "" },
+        no_separator = { "1", "" },
+        not_a_separator = { "1-2", "-2" },
+        no_minor = { "1.", "" },
+        leading_zero = { "1.02", "02" },
+        overflow = { "18446744073709551616.0", "18446744073709551616.0" },
+        patch_leading_zero = { "1.2.03", "03" },
+    )]
+    fn rejected(input: &str, at: &str) {
+        let mut input = input;
+        let result: Result<Version, InputError<&str>> = version(&mut input);
+
+        assert_eq!(result, Err(InputError::at(at)));
+    }
+
+    #[test]
+    fn base_version_stops_after_minor() {
+        let mut input = "1.2.3";
+        let result: Result<BaseVersion, InputError<&str>> = base_version(&mut input);
+
+        assert_eq!(result, Ok(BaseVersion::new(1, 2)));
+        assert_eq!(input, ".3");
+    }
+
+    #[test]
+    fn full_version_requires_patch() {
+        let result: Result<FullVersion, ErrMode<InputError<&str>>> =
+            full_version.parse_next(&mut "1.2 ");
+
+        assert_eq!(result, Err(ErrMode::Backtrack(InputError::at(" "))));
+    }
+
+    #[test]
+    fn bytes() {
+        let mut input = &b"1.2.3\n"[..];
+        let result: Result<FullVersion, InputError<&[u8]>> = full_version(&mut input);
+
+        assert_eq!(result, Ok(FullVersion::new(1, 2, 3)));
+        assert_eq!(input, b"\n");
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct NumberErrorKind(Option<NumberError>);
+
+    impl<I: Stream> ParserError<I> for NumberErrorKind {
+        type Inner = Self;
+
+        fn from_input(_: &I) -> Self {
+            Self(None)
+        }
+
+        fn into_inner(self) -> Result<Self::Inner, Self> {
+            Ok(self)
+        }
+    }
+
+    impl<I> FromExternalError<I, NumberError> for NumberErrorKind {
+        fn from_external_error(_: &I, e: NumberError) -> Self {
+            Self(Some(e))
+        }
+    }
+
+    #[yare::parameterized(
+        leading_zero = { "01", Some(NumberError::LeadingZero) },
+        overflow = { "18446744073709551616", Some(NumberError::Overflow) },
+        not_a_number = { "x", None },
+    )]
+    fn number_error(input: &str, expected: Option<NumberError>) {
+        let mut input = input;
+        let result: Result<u64, NumberErrorKind> = component(&mut input);
+
+        assert_eq!(result, Err(NumberErrorKind(expected)));
+    }
+}